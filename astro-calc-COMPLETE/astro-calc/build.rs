@@ -14,6 +14,7 @@ fn main() {
         .file(sweph_dir.join("swejpl.c"))
         .file(sweph_dir.join("swemmoon.c"))
         .file(sweph_dir.join("swemplan.c"))
+        .file(sweph_dir.join("swehel.c"))
         .include(&sweph_dir)
         .warnings(false)
         .compile("swe");
@@ -0,0 +1,160 @@
+use crate::aspects::{calculate_cross_aspects, Aspect, AspectEntity, AspectType};
+use crate::chart::Chart;
+use crate::sweph::{calculate_jd, tropical_to_sidereal, ZodiacMode};
+use chrono::{DateTime, Utc};
+
+/// Orb for conjunctions and oppositions with antiscia
+pub const ANTISCIA_ORB: f64 = 1.5;
+
+/// Which reflection a natal point falls on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReflectionKind {
+    Antiscion,       // Mirrored across the solstices (0° Cancer / 0° Capricorn)
+    ContraAntiscion, // Mirrored across the equinoxes (0° Aries / 0° Libra)
+}
+
+impl ReflectionKind {
+    pub fn to_string(&self) -> &str {
+        match self {
+            ReflectionKind::Antiscion => "antiscion",
+            ReflectionKind::ContraAntiscion => "contra-antiscion",
+        }
+    }
+}
+
+/// Solstitial and equinoctial reflections of one chart point
+#[derive(Debug, Clone)]
+pub struct AntisciaPoint {
+    pub entity: AspectEntity,
+    pub longitude: f64,
+    pub antiscion: f64,
+    pub contra_antiscion: f64,
+}
+
+/// A natal point conjunct another point's antiscion or contra-antiscion
+/// `aspect.entity1` is the natal point, `aspect.entity2` the reflected one;
+/// the aspect is a conjunction with the reflection (an opposition to the
+/// antiscion is reported as a conjunction with the contra-antiscion)
+#[derive(Debug, Clone)]
+pub struct AntisciaContact {
+    pub kind: ReflectionKind,
+    pub aspect: Aspect,
+}
+
+#[derive(Debug, Clone)]
+pub struct AntisciaReport {
+    pub points: Vec<AntisciaPoint>,
+    pub contacts: Vec<AntisciaContact>,
+}
+
+/// Mirror a longitude across an axis through `axis` and `axis + 180`
+fn reflect(longitude: f64, axis: f64) -> f64 {
+    (2.0 * axis - longitude).rem_euclid(360.0)
+}
+
+/// Antiscia and contra-antiscia for every body and angle of a chart
+/// The reflection axes are the tropical solstices and equinoxes; in the sidereal
+/// frame they sit at their sidereal longitude for `datetime` (the chart moment)
+pub fn calculate_antiscia(
+    chart: &Chart,
+    datetime: DateTime<Utc>,
+    mode: ZodiacMode,
+) -> Result<AntisciaReport, String> {
+    let (solstice, equinox) = match mode {
+        ZodiacMode::Tropical => (90.0, 0.0),
+        ZodiacMode::Sidereal => {
+            let jd = calculate_jd(&datetime);
+            (
+                tropical_to_sidereal(jd, 90.0)?,
+                tropical_to_sidereal(jd, 0.0)?,
+            )
+        }
+    };
+
+    let points: Vec<AntisciaPoint> = chart
+        .points()
+        .into_iter()
+        .map(|(entity, longitude)| AntisciaPoint {
+            entity,
+            longitude,
+            antiscion: reflect(longitude, solstice),
+            contra_antiscion: reflect(longitude, equinox),
+        })
+        .collect();
+
+    // Run the aspect engine against a chart holding the antiscia
+    let mut mirrored = chart.clone();
+    mirrored.fixed_stars = None;
+    for pos in mirrored.positions.iter_mut() {
+        pos.longitude = reflect(pos.longitude, solstice);
+    }
+    for angle in mirrored.angles.iter_mut() {
+        angle.longitude = reflect(angle.longitude, solstice);
+    }
+
+    let index = |entity: &AspectEntity| points.iter().position(|p| p.entity == *entity);
+    let mut contacts: Vec<AntisciaContact> =
+        calculate_cross_aspects(chart, &mirrored, ANTISCIA_ORB)
+            .into_iter()
+            // A point on its own antiscion is simply on the axis; A/B and B/A are the same contact
+            .filter(|a| index(&a.entity1) < index(&a.entity2))
+            .filter_map(|aspect| {
+                let kind = match aspect.aspect_type {
+                    AspectType::Conjunction => ReflectionKind::Antiscion,
+                    AspectType::Opposition => ReflectionKind::ContraAntiscion,
+                    _ => return None,
+                };
+                Some(AntisciaContact {
+                    kind,
+                    aspect: Aspect {
+                        aspect_type: AspectType::Conjunction,
+                        ..aspect
+                    },
+                })
+            })
+            .collect();
+    contacts.sort_by(|a, b| a.aspect.orb.partial_cmp(&b.aspect.orb).unwrap());
+
+    Ok(AntisciaReport { points, contacts })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::{test_position as position, CelestialBody};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_tropical_and_sidereal_antiscia() {
+        let mut chart = Chart::new();
+        chart.positions = vec![
+            position(CelestialBody::Sun, 40.0), // 10° Taurus -> antiscion 20° Leo
+            position(CelestialBody::Venus, 140.5), // On the Sun's antiscion
+            position(CelestialBody::Mars, 320.0), // On the Sun's contra-antiscion
+        ];
+        let datetime = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+
+        let report = calculate_antiscia(&chart, datetime, ZodiacMode::Tropical).unwrap();
+        assert!((report.points[0].antiscion - 140.0).abs() < 1e-9);
+        assert!((report.points[0].contra_antiscion - 320.0).abs() < 1e-9);
+
+        let sun = AspectEntity::Body(CelestialBody::Sun);
+        let kinds: Vec<(ReflectionKind, String)> = report
+            .contacts
+            .iter()
+            .filter(|c| c.aspect.entity1 == sun)
+            .map(|c| (c.kind, c.aspect.entity2.to_string()))
+            .collect();
+        assert!(kinds.contains(&(ReflectionKind::Antiscion, "Venus".to_string())));
+        assert!(kinds.contains(&(ReflectionKind::ContraAntiscion, "Mars".to_string())));
+
+        // Sidereal axes shift with the ayanamsa but reflections stay symmetric
+        crate::sweph::init_sweph();
+        let sidereal = calculate_antiscia(&chart, datetime, ZodiacMode::Sidereal).unwrap();
+        let sun = &sidereal.points[0];
+        assert!((sun.antiscion - 140.0).abs() > 40.0);
+        let axis = (sun.longitude + sun.antiscion) / 2.0;
+        let equinox = (sun.longitude + sun.contra_antiscion) / 2.0;
+        assert!(((axis - equinox).rem_euclid(180.0) - 90.0).abs() < 1e-6);
+    }
+}
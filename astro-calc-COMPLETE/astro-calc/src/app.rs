@@ -1,11 +1,12 @@
 use dioxus::prelude::*;
-use crate::components::{NatalTab, SynastryTab, TransitsTab};
+use crate::components::{NatalTab, ProgressionsTab, SynastryTab, TransitsTab};
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum Tab {
     NatalChart,
     Synastry,
     Transits,
+    Progressions,
 }
 
 #[component]
@@ -48,6 +49,12 @@ pub fn App() -> Element {
                     onclick: move |_| active_tab.set(Tab::Transits),
                     "🔄 Transits"
                 }
+                
+                button {
+                    class: if *active_tab.read() == Tab::Progressions { "tab-button active" } else { "tab-button" },
+                    onclick: move |_| active_tab.set(Tab::Progressions),
+                    "⏳ Progressions"
+                }
             }
 
             // Main Content Area
@@ -58,6 +65,7 @@ pub fn App() -> Element {
                     Tab::NatalChart => rsx! { NatalTab {} },
                     Tab::Synastry => rsx! { SynastryTab {} },
                     Tab::Transits => rsx! { TransitsTab {} },
+                    Tab::Progressions => rsx! { ProgressionsTab {} },
                 }
             }
 
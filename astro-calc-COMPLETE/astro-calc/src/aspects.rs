@@ -1,5 +1,6 @@
 use crate::chart::{AnglePoint, CelestialBody, Chart};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Aspect types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -85,11 +86,11 @@ pub enum AspectEntity {
     Angle(AnglePoint),
 }
 
-impl AspectEntity {
-    pub fn to_string(&self) -> String {
+impl fmt::Display for AspectEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            AspectEntity::Body(body) => body.to_string().to_string(),
-            AspectEntity::Angle(angle) => angle.to_string().to_string(),
+            AspectEntity::Body(body) => write!(f, "{}", body),
+            AspectEntity::Angle(angle) => f.write_str(angle.to_string()),
         }
     }
 }
@@ -108,7 +109,7 @@ impl Aspect {
         format!(
             "{} {} {}",
             self.aspect_type.symbol(),
-            self.entity2.to_string(),
+            self.entity2,
             if self.orb < 1.0 {
                 format!("(exact {:.1}°)", self.orb)
             } else {
@@ -118,13 +119,10 @@ impl Aspect {
     }
 }
 
-/// Calculate angular separation between two longitudes
-fn angular_separation(lon1: f64, lon2: f64) -> f64 {
-    let mut diff = (lon2 - lon1).abs();
-    if diff > 180.0 {
-        diff = 360.0 - diff;
-    }
-    diff
+/// Calculate angular separation between two longitudes (0-180)
+pub(crate) fn angular_separation(lon1: f64, lon2: f64) -> f64 {
+    let diff = (lon2 - lon1).rem_euclid(360.0);
+    diff.min(360.0 - diff)
 }
 
 /// Check if two longitudes form an aspect
@@ -171,17 +169,7 @@ pub fn calculate_aspects(chart: &Chart) -> Vec<(AspectEntity, Vec<Aspect>)> {
     let mut result = Vec::new();
 
     // Get all entities with their longitudes
-    let mut entities: Vec<(AspectEntity, f64)> = Vec::new();
-
-    // Add bodies
-    for pos in &chart.positions {
-        entities.push((AspectEntity::Body(pos.body), pos.longitude));
-    }
-
-    // Add angles
-    for angle_pos in &chart.angles {
-        entities.push((AspectEntity::Angle(angle_pos.angle), angle_pos.longitude));
-    }
+    let entities = chart.points();
 
     // Calculate aspects for each entity
     for (i, (entity1, lon1)) in entities.iter().enumerate() {
@@ -209,6 +197,25 @@ pub fn calculate_aspects(chart: &Chart) -> Vec<(AspectEntity, Vec<Aspect>)> {
     result
 }
 
+/// Calculate aspects from one chart's points to another's (e.g. progressed to natal)
+/// entity1 is always from `from`, entity2 from `to`; only aspects within `max_orb` are kept
+pub fn calculate_cross_aspects(from: &Chart, to: &Chart, max_orb: f64) -> Vec<Aspect> {
+    let targets = to.points();
+    let mut result = Vec::new();
+
+    for (entity1, lon1) in from.points() {
+        for (entity2, lon2) in &targets {
+            if let Some(aspect) = check_aspect(lon1, *lon2, &entity1, entity2) {
+                if aspect.orb <= max_orb {
+                    result.push(aspect);
+                }
+            }
+        }
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
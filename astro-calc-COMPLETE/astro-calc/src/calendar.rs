@@ -0,0 +1,126 @@
+use chrono::{DateTime, Utc};
+
+/// One entry for calendar export (iCalendar or CSV)
+#[derive(Debug, Clone)]
+pub struct CalendarEvent {
+    pub start: DateTime<Utc>,
+    pub end: Option<DateTime<Utc>>, // None = instantaneous event
+    pub summary: String,
+    pub description: String,
+}
+
+/// iCalendar UTC timestamp (RFC 5545 "form #2")
+fn ics_timestamp(datetime: &DateTime<Utc>) -> String {
+    datetime.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape TEXT values (RFC 5545 §3.3.11)
+fn ics_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold content lines longer than 75 octets (RFC 5545 §3.1)
+fn ics_fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+    folded
+}
+
+/// Render events as an iCalendar (.ics) file
+pub fn events_to_ics(events: &[CalendarEvent], calendar_name: &str) -> String {
+    let stamp = ics_timestamp(&Utc::now());
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//astro-calc//Astrology Calendar//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", ics_escape(calendar_name)),
+    ];
+
+    for (i, event) in events.iter().enumerate() {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!(
+            "UID:{}-{}@astro-calc",
+            ics_timestamp(&event.start),
+            i
+        ));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!("DTSTART:{}", ics_timestamp(&event.start)));
+        lines.push(format!(
+            "DTEND:{}",
+            ics_timestamp(event.end.as_ref().unwrap_or(&event.start))
+        ));
+        lines.push(format!("SUMMARY:{}", ics_escape(&event.summary)));
+        if !event.description.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", ics_escape(&event.description)));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+    lines
+        .iter()
+        .map(|l| ics_fold(l))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+/// Quote a CSV field when it contains separators, quotes or newlines
+pub fn csv_field(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Render events as CSV (start, end, summary, description)
+pub fn events_to_csv(events: &[CalendarEvent]) -> String {
+    let mut lines = vec!["start,end,summary,description".to_string()];
+    for event in events {
+        lines.push(
+            [
+                event.start.to_rfc3339(),
+                event.end.map(|e| e.to_rfc3339()).unwrap_or_default(),
+                csv_field(&event.summary),
+                csv_field(&event.description),
+            ]
+            .join(","),
+        );
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_ics_escaping_and_folding() {
+        let event = CalendarEvent {
+            start: Utc.with_ymd_and_hms(2025, 3, 29, 10, 58, 0).unwrap(),
+            end: None,
+            summary: "New Moon; Solar Eclipse, partial".to_string(),
+            description: "x".repeat(200),
+        };
+        let ics = events_to_ics(&[event], "Moon");
+
+        assert!(ics.contains("DTSTART:20250329T105800Z\r\n"));
+        assert!(ics.contains("SUMMARY:New Moon\\; Solar Eclipse\\, partial\r\n"));
+        assert!(ics.lines().all(|l| l.trim_end_matches('\r').len() <= 75));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    }
+}
@@ -1,4 +1,47 @@
+use crate::aspects::AspectEntity;
+use crate::fixed_stars::FixedStarReport;
+use crate::house_rulers::HouseRulerReport;
+use crate::lots::Lot;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Classical elements (triplicities)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Element {
+    Fire,
+    Earth,
+    Air,
+    Water,
+}
+
+impl Element {
+    pub fn to_string(&self) -> &str {
+        match self {
+            Element::Fire => "Fire",
+            Element::Earth => "Earth",
+            Element::Air => "Air",
+            Element::Water => "Water",
+        }
+    }
+}
+
+/// Modalities (quadruplicities)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Modality {
+    Cardinal,
+    Fixed,
+    Mutable,
+}
+
+impl Modality {
+    pub fn to_string(&self) -> &str {
+        match self {
+            Modality::Cardinal => "Cardinal",
+            Modality::Fixed => "Fixed",
+            Modality::Mutable => "Mutable",
+        }
+    }
+}
 
 /// Zodiac signs
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -37,6 +80,55 @@ impl ZodiacSign {
         }
     }
 
+    /// Traditional (pre-Uranus) domicile ruler
+    pub fn traditional_ruler(&self) -> CelestialBody {
+        match self {
+            ZodiacSign::Aries | ZodiacSign::Scorpio => CelestialBody::Mars,
+            ZodiacSign::Taurus | ZodiacSign::Libra => CelestialBody::Venus,
+            ZodiacSign::Gemini | ZodiacSign::Virgo => CelestialBody::Mercury,
+            ZodiacSign::Cancer => CelestialBody::Moon,
+            ZodiacSign::Leo => CelestialBody::Sun,
+            ZodiacSign::Sagittarius | ZodiacSign::Pisces => CelestialBody::Jupiter,
+            ZodiacSign::Capricorn | ZodiacSign::Aquarius => CelestialBody::Saturn,
+        }
+    }
+
+    /// Modern domicile ruler (outer planets for Scorpio, Aquarius and Pisces)
+    pub fn modern_ruler(&self) -> CelestialBody {
+        match self {
+            ZodiacSign::Scorpio => CelestialBody::Pluto,
+            ZodiacSign::Aquarius => CelestialBody::Uranus,
+            ZodiacSign::Pisces => CelestialBody::Neptune,
+            _ => self.traditional_ruler(),
+        }
+    }
+
+    /// Position in the zodiac (Aries = 0)
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn opposite(&self) -> ZodiacSign {
+        ZodiacSign::from_longitude(((self.index() + 6) % 12) as f64 * 30.0)
+    }
+
+    pub fn element(&self) -> Element {
+        match self.index() % 4 {
+            0 => Element::Fire,
+            1 => Element::Earth,
+            2 => Element::Air,
+            _ => Element::Water,
+        }
+    }
+
+    pub fn modality(&self) -> Modality {
+        match self.index() % 3 {
+            0 => Modality::Cardinal,
+            1 => Modality::Fixed,
+            _ => Modality::Mutable,
+        }
+    }
+
     pub fn to_string(&self) -> &str {
         match self {
             ZodiacSign::Aries => "Aries",
@@ -72,11 +164,25 @@ pub enum CelestialBody {
     Chiron,
     Fortuna,
     Vertex,
+
+    // Additional points (opt-in per chart)
+    MeanNode,
+    SouthNode,  // Opposite the True Node
+    MeanLilith, // Black Moon Lilith (mean lunar apogee)
+    TrueLilith, // Osculating lunar apogee
+    Ceres,
+    Pallas,
+    Juno,
+    Vesta,
+    Pholus,
+    Eris,
+    Asteroid(u32), // Minor planet by catalogue number (needs its .se1 file)
+    Lot(Lot),      // Arabic Part other than Fortune
 }
 
-impl CelestialBody {
-    pub fn to_string(&self) -> &str {
-        match self {
+impl fmt::Display for CelestialBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
             CelestialBody::Sun => "Sun",
             CelestialBody::Moon => "Moon",
             CelestialBody::Mercury => "Mercury",
@@ -91,9 +197,25 @@ impl CelestialBody {
             CelestialBody::Chiron => "Chiron",
             CelestialBody::Fortuna => "Fortuna",
             CelestialBody::Vertex => "Vertex",
-        }
+            CelestialBody::MeanNode => "Mean Node",
+            CelestialBody::SouthNode => "South Node",
+            CelestialBody::MeanLilith => "Lilith",
+            CelestialBody::TrueLilith => "True Lilith",
+            CelestialBody::Ceres => "Ceres",
+            CelestialBody::Pallas => "Pallas",
+            CelestialBody::Juno => "Juno",
+            CelestialBody::Vesta => "Vesta",
+            CelestialBody::Pholus => "Pholus",
+            CelestialBody::Eris => "Eris",
+            CelestialBody::Asteroid(number) => return write!(f, "Asteroid {}", number),
+            CelestialBody::Lot(lot) => return write!(f, "Lot of {}", lot.to_string()),
+        };
+        f.write_str(name)
     }
+}
+
 
+impl CelestialBody {
     /// Get default orb for this body in aspects
     pub fn orb(&self) -> f64 {
         match self {
@@ -121,6 +243,23 @@ impl CelestialBody {
             CelestialBody::Vertex,
         ]
     }
+
+    /// Optional bodies that can be added to a chart's selection.
+    /// Numbered asteroids are not listed; use `CelestialBody::Asteroid(n)`.
+    pub fn additional() -> Vec<Self> {
+        vec![
+            CelestialBody::MeanNode,
+            CelestialBody::SouthNode,
+            CelestialBody::MeanLilith,
+            CelestialBody::TrueLilith,
+            CelestialBody::Ceres,
+            CelestialBody::Pallas,
+            CelestialBody::Juno,
+            CelestialBody::Vesta,
+            CelestialBody::Pholus,
+            CelestialBody::Eris,
+        ]
+    }
 }
 
 /// Angle points
@@ -139,6 +278,31 @@ impl AnglePoint {
     }
 }
 
+/// Day or night chart, from the Sun's altitude at birth
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Sect {
+    Day,
+    Night,
+}
+
+impl Sect {
+    pub fn to_string(&self) -> &str {
+        match self {
+            Sect::Day => "Day",
+            Sect::Night => "Night",
+        }
+    }
+}
+
+/// Local horizon coordinates of a position at the chart's place
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct HorizonPosition {
+    pub azimuth: f64,           // Degrees from north, through east (0-360)
+    pub true_altitude: f64,     // Geometric altitude in degrees
+    pub apparent_altitude: f64, // Altitude including atmospheric refraction
+    pub above_horizon: bool,    // Visible above the horizon (apparent altitude > 0)
+}
+
 /// A celestial position
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Position {
@@ -146,6 +310,8 @@ pub struct Position {
     pub longitude: f64,  // Ecliptic longitude (0-360)
     pub retrograde: bool,
     pub house: u8,       // House number (1-12)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub horizon: Option<HorizonPosition>,
 }
 
 impl Position {
@@ -158,6 +324,18 @@ impl Position {
     }
 }
 
+/// Test fixture: a direct body at `longitude`, placed in house 1
+#[cfg(test)]
+pub(crate) fn test_position(body: CelestialBody, longitude: f64) -> Position {
+    Position {
+        body,
+        longitude,
+        retrograde: false,
+        house: 1,
+        horizon: None,
+    }
+}
+
 /// Angle positions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnglePosition {
@@ -215,6 +393,18 @@ pub struct Chart {
     pub houses: HouseCusps,
     pub name: Option<String>,
     pub gender: Option<String>,
+    #[serde(default)]
+    pub sect: Option<Sect>, // Only set for charts cast for a birth place
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed_stars: Option<FixedStarReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub house_rulers: Option<HouseRulerReport>, // Set for charts cast with real houses
+}
+
+impl Default for Chart {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Chart {
@@ -225,6 +415,9 @@ impl Chart {
             houses: HouseCusps::new([0.0; 12]),
             name: None,
             gender: None,
+            sect: None,
+            fixed_stars: None,
+            house_rulers: None,
         }
     }
 
@@ -241,4 +434,17 @@ impl Chart {
     pub fn get_angle(&self, angle: AnglePoint) -> Option<&AnglePosition> {
         self.angles.iter().find(|a| a.angle == angle)
     }
+
+    /// Positions then angles, as aspectable points with their longitudes
+    pub fn points(&self) -> Vec<(AspectEntity, f64)> {
+        self.positions
+            .iter()
+            .map(|p| (AspectEntity::Body(p.body), p.longitude))
+            .chain(
+                self.angles
+                    .iter()
+                    .map(|a| (AspectEntity::Angle(a.angle), a.longitude)),
+            )
+            .collect()
+    }
 }
@@ -0,0 +1,389 @@
+use dioxus::prelude::*;
+use astro_calc::ChartInput;
+use chrono::{DateTime, Utc, NaiveDateTime, NaiveDate, NaiveTime, TimeZone};
+use chrono_tz::Tz;
+
+/// Guess IANA timezone from coordinates
+/// This is a simplified mapping for common locations. Users can override if needed.
+/// 
+/// For production use, consider using a timezone lookup service like:
+/// - https://timezonedb.com/api
+/// - https://www.geonames.org/export/web-services.html
+/// 
+/// This function provides reasonable defaults for major regions.
+pub fn guess_timezone_from_coords(lat: f64, lon: f64) -> &'static str {
+    // North America
+    if lat >= 25.0 && lat <= 72.0 {
+        if lon >= -170.0 && lon <= -140.0 {
+            return "America/Anchorage";  // Alaska
+        } else if lon >= -125.0 && lon <= -114.0 {
+            return "America/Los_Angeles";  // Pacific
+        } else if lon >= -115.0 && lon <= -104.0 {
+            if lat >= 31.0 && lat <= 37.0 && lon >= -114.0 && lon <= -109.0 {
+                return "America/Phoenix";  // Arizona (no DST)
+            }
+            return "America/Denver";  // Mountain
+        } else if lon >= -105.0 && lon <= -87.0 {
+            return "America/Chicago";  // Central
+        } else if lon >= -88.0 && lon <= -67.0 {
+            return "America/New_York";  // Eastern
+        }
+    }
+    
+    // Europe
+    if lat >= 35.0 && lat <= 71.0 && lon >= -10.0 && lon <= 40.0 {
+        if lon >= -10.0 && lon <= 0.0 {
+            return "Europe/London";  // UK/Ireland/Portugal
+        } else if lon >= 0.0 && lon <= 15.0 {
+            return "Europe/Paris";  // Western Europe
+        } else if lon >= 15.0 && lon <= 30.0 {
+            return "Europe/Berlin";  // Central Europe
+        } else {
+            return "Europe/Moscow";  // Eastern Europe
+        }
+    }
+    
+    // Asia
+    if lat >= -10.0 && lat <= 55.0 && lon >= 60.0 && lon <= 180.0 {
+        if lon >= 60.0 && lon <= 90.0 {
+            return "Asia/Kolkata";  // India
+        } else if lon >= 100.0 && lon <= 110.0 {
+            return "Asia/Bangkok";  // SE Asia
+        } else if lon >= 115.0 && lon <= 125.0 {
+            return "Asia/Shanghai";  // China
+        } else if lon >= 135.0 && lon <= 145.0 {
+            return "Asia/Tokyo";  // Japan
+        }
+    }
+    
+    // Australia
+    if lat >= -45.0 && lat <= -10.0 && lon >= 110.0 && lon <= 155.0 {
+        if lon >= 110.0 && lon <= 130.0 {
+            return "Australia/Perth";  // Western
+        } else if lon >= 135.0 && lon <= 145.0 {
+            return "Australia/Adelaide";  // Central
+        } else {
+            return "Australia/Sydney";  // Eastern
+        }
+    }
+    
+    // South America
+    if lat >= -55.0 && lat <= 12.0 && lon >= -82.0 && lon <= -34.0 {
+        if lon >= -82.0 && lon <= -75.0 {
+            return "America/Lima";  // Peru/Colombia
+        } else if lon >= -75.0 && lon <= -50.0 {
+            return "America/Sao_Paulo";  // Brazil
+        } else {
+            return "America/Argentina/Buenos_Aires";
+        }
+    }
+    
+    // Africa
+    if lat >= -35.0 && lat <= 37.0 && lon >= -17.0 && lon <= 52.0 {
+        if lon >= 25.0 && lon <= 32.0 && lat >= -30.0 && lat <= -22.0 {
+            return "Africa/Johannesburg";  // South Africa
+        } else if lon >= 30.0 && lon <= 32.0 && lat >= 29.0 && lat <= 32.0 {
+            return "Africa/Cairo";  // Egypt
+        } else {
+            return "Africa/Lagos";  // West Africa
+        }
+    }
+    
+    // Default: UTC
+    "UTC"
+}
+
+/// Parse a form date ("YYYY-MM-DD") and time ("HH:MM") in an IANA timezone into UTC
+/// `label` names the field in error messages, e.g. "birth", "transit" or "Person 2"
+pub fn parse_local_datetime(date: &str, time: &str, timezone: &str, label: &str) -> Result<DateTime<Utc>, String> {
+    let tz: Tz = timezone.parse().map_err(|_| {
+        format!(
+            "Invalid {} timezone: {}. Use IANA format (e.g., America/Los_Angeles)",
+            label, timezone
+        )
+    })?;
+
+    let date_parts: Vec<&str> = date.split('-').collect();
+    let time_parts: Vec<&str> = time.split(':').collect();
+    if date_parts.len() != 3 || time_parts.len() != 2 {
+        return Err(format!("Invalid {} date or time format", label));
+    }
+
+    let year: i32 = date_parts[0].parse().unwrap_or(0);
+    let month: u32 = date_parts[1].parse().unwrap_or(0);
+    let day: u32 = date_parts[2].parse().unwrap_or(0);
+    let hour: u32 = time_parts[0].parse().unwrap_or(0);
+    let minute: u32 = time_parts[1].parse().unwrap_or(0);
+
+    let naive_date = NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(|| format!("Invalid {} date: {}-{}-{}", label, year, month, day))?;
+    let naive_time = NaiveTime::from_hms_opt(hour, minute, 0)
+        .ok_or_else(|| format!("Invalid {} time: {}:{}", label, hour, minute))?;
+
+    let local_dt = tz
+        .from_local_datetime(&NaiveDateTime::new(naive_date, naive_time))
+        .single()
+        .ok_or_else(|| format!("Ambiguous {} time (DST transition)", label))?;
+    Ok(local_dt.with_timezone(&Utc))
+}
+
+/// Signals behind a birth data section of a form
+#[derive(Clone, Copy, PartialEq)]
+pub struct NatalFields {
+    pub name: Signal<String>,
+    pub gender: Signal<String>,
+    pub birth_date: Signal<String>,
+    pub birth_time: Signal<String>,
+    pub timezone: Signal<String>,
+    pub latitude: Signal<String>,
+    pub longitude: Signal<String>,
+}
+
+pub fn use_natal_fields(gender: &'static str) -> NatalFields {
+    NatalFields {
+        name: use_signal(|| String::from("")),
+        gender: use_signal(|| String::from(gender)),
+        birth_date: use_signal(|| String::from("")),
+        birth_time: use_signal(|| String::from("")),
+        timezone: use_signal(|| String::from("America/New_York")),
+        latitude: use_signal(|| String::from("")),
+        longitude: use_signal(|| String::from("")),
+    }
+}
+
+impl NatalFields {
+    /// Validate the fields and build the chart input
+    /// `label` names the person or chart in error messages, e.g. "natal" or "Person 1"
+    pub fn to_chart_input(self, label: &str) -> Result<ChartInput, String> {
+        if self.name.read().is_empty() || self.birth_date.read().is_empty() || self.birth_time.read().is_empty() {
+            return Err(format!("Please complete all {} fields", label));
+        }
+
+        let lat: f64 = self.latitude.read().parse().map_err(|_| format!("Invalid {} latitude", label))?;
+        let lon: f64 = self.longitude.read().parse().map_err(|_| format!("Invalid {} longitude", label))?;
+
+        let natal_dt = parse_local_datetime(
+            &self.birth_date.read(),
+            &self.birth_time.read(),
+            &self.timezone.read(),
+            label,
+        )?;
+
+        Ok(ChartInput::new(natal_dt, lat, lon)
+            .with_name(self.name.read().clone())
+            .with_gender(self.gender.read().clone()))
+    }
+
+    /// Auto-guess the timezone once both coordinates parse
+    fn guess_timezone(mut self) {
+        let coords = (self.latitude.read().parse::<f64>(), self.longitude.read().parse::<f64>());
+        if let (Ok(lat), Ok(lon)) = coords {
+            self.timezone.set(guess_timezone_from_coords(lat, lon).to_string());
+        }
+    }
+}
+
+/// Signals behind the second date of a form (transit or progressed date)
+#[derive(Clone, Copy, PartialEq)]
+pub struct DateFields {
+    pub date: Signal<String>,
+    pub time: Signal<String>,
+    pub timezone: Signal<String>,
+}
+
+pub fn use_date_fields() -> DateFields {
+    DateFields {
+        date: use_signal(|| String::from("")),
+        time: use_signal(|| String::from("12:00")),
+        timezone: use_signal(|| String::from("America/New_York")),
+    }
+}
+
+impl DateFields {
+    /// `label` is the lowercase field name used in error messages
+    pub fn to_utc(self, label: &str) -> Result<DateTime<Utc>, String> {
+        if self.date.read().is_empty() {
+            return Err(format!("Please enter a {} date", label));
+        }
+        parse_local_datetime(&self.date.read(), &self.time.read(), &self.timezone.read(), label)
+    }
+}
+
+/// Name, gender, birth date, time, timezone and coordinates; the caller adds the heading
+/// `radio_group` keeps the gender radios of several forms on one tab apart
+#[component]
+pub fn NatalForm(fields: NatalFields, radio_group: String) -> Element {
+    let NatalFields {
+        mut name,
+        mut gender,
+        mut birth_date,
+        mut birth_time,
+        mut timezone,
+        mut latitude,
+        mut longitude,
+    } = fields;
+
+    rsx! {
+        div { class: "form-group",
+            label { "Name" }
+            input {
+                r#type: "text",
+                value: "{name}",
+                oninput: move |evt| name.set(evt.value()),
+                placeholder: "Enter name"
+            }
+        }
+
+        div { class: "form-group",
+            label { "Gender" }
+            div { class: "radio-group",
+                for option in ["Male", "Female", "Other"] {
+                    label { class: "radio-label",
+                        input {
+                            r#type: "radio",
+                            name: "{radio_group}",
+                            checked: *gender.read() == option,
+                            onchange: move |_| gender.set(option.to_string())
+                        }
+                        "{option}"
+                    }
+                }
+            }
+        }
+
+        div { class: "form-row",
+            div { class: "form-group",
+                label { "Birth Date" }
+                input {
+                    r#type: "date",
+                    value: "{birth_date}",
+                    oninput: move |evt| birth_date.set(evt.value())
+                }
+            }
+            div { class: "form-group",
+                label { "Birth Time" }
+                input {
+                    r#type: "time",
+                    value: "{birth_time}",
+                    oninput: move |evt| birth_time.set(evt.value())
+                }
+            }
+        }
+
+        div { class: "form-group",
+            label { "Timezone" }
+            input {
+                r#type: "text",
+                value: "{timezone}",
+                oninput: move |evt| timezone.set(evt.value()),
+                placeholder: "e.g., America/Los_Angeles"
+            }
+            p { class: "hint", "IANA timezone format (handles DST automatically)" }
+        }
+
+        div { class: "form-row",
+            div { class: "form-group",
+                label { "Latitude" }
+                input {
+                    r#type: "text",
+                    value: "{latitude}",
+                    oninput: move |evt| {
+                        latitude.set(evt.value());
+                        fields.guess_timezone();
+                    },
+                    placeholder: "e.g., 36.7477"
+                }
+            }
+            div { class: "form-group",
+                label { "Longitude" }
+                input {
+                    r#type: "text",
+                    value: "{longitude}",
+                    oninput: move |evt| {
+                        longitude.set(evt.value());
+                        fields.guess_timezone();
+                    },
+                    placeholder: "e.g., -119.7724"
+                }
+            }
+        }
+    }
+}
+
+/// Date, time and timezone of the second chart, headed and labelled with `label`
+#[component]
+pub fn DateForm(fields: DateFields, label: String, hint: String) -> Element {
+    let DateFields { mut date, mut time, mut timezone } = fields;
+
+    rsx! {
+        h3 { "{label} Date" }
+
+        div { class: "form-row",
+            div { class: "form-group",
+                label { "{label} Date" }
+                input {
+                    r#type: "date",
+                    value: "{date}",
+                    oninput: move |evt| date.set(evt.value())
+                }
+            }
+            div { class: "form-group",
+                label { "{label} Time" }
+                input {
+                    r#type: "time",
+                    value: "{time}",
+                    oninput: move |evt| time.set(evt.value())
+                }
+            }
+        }
+
+        div { class: "form-group",
+            label { "{label} Timezone" }
+            input {
+                r#type: "text",
+                value: "{timezone}",
+                oninput: move |evt| timezone.set(evt.value()),
+                placeholder: "e.g., America/Los_Angeles"
+            }
+            p { class: "hint",
+                "{hint}"
+                br {}
+                "IANA timezone format (handles DST automatically)"
+            }
+        }
+    }
+}
+
+/// Right-hand panel showing the formatted report, with a copy button
+#[component]
+pub fn ResultsPanel(results: Signal<String>, placeholder: String) -> Element {
+    rsx! {
+        div { class: "results-panel",
+            div { class: "results-display",
+                if results.read().is_empty() {
+                    p { class: "results-placeholder", "{placeholder}" }
+                } else {
+                    pre {
+                        style: "white-space: pre-wrap; font-family: 'Courier New', monospace;",
+                        "{results}"
+                    }
+                }
+            }
+
+            if !results.read().is_empty() {
+                div { class: "results-actions",
+                    button {
+                        class: "btn-secondary",
+                        onclick: move |_| {
+                            use copypasta::{ClipboardContext, ClipboardProvider};
+                            if let Ok(mut ctx) = ClipboardContext::new() {
+                                let _ = ctx.set_contents(results.read().clone());
+                            }
+                        },
+                        "📋 Copy to Clipboard"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
+mod chart_form;
 mod natal;
+mod progressions;
 mod synastry;
 mod transits;
 
 pub use natal::NatalTab;
+pub use progressions::ProgressionsTab;
 pub use synastry::SynastryTab;
 pub use transits::TransitsTab;
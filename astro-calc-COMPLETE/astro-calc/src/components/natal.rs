@@ -1,7 +1,6 @@
 use dioxus::prelude::*;
-use astro_calc::{calculate_chart, format_natal_chart, ChartInput};
-use chrono::{Utc, NaiveDateTime, NaiveDate, NaiveTime, TimeZone};
-use chrono_tz::Tz;
+use astro_calc::{calculate_chart, format_natal_chart, CelestialBody, ChartInput, FixedStarOptions, Lot, StarList};
+use super::chart_form::{guess_timezone_from_coords, parse_local_datetime, ResultsPanel};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -40,96 +39,6 @@ struct LocationResult {
     longitude: f64,
 }
 
-/// Guess IANA timezone from coordinates
-/// This is a simplified mapping for common locations. Users can override if needed.
-/// 
-/// For production use, consider using a timezone lookup service like:
-/// - https://timezonedb.com/api
-/// - https://www.geonames.org/export/web-services.html
-/// 
-/// This function provides reasonable defaults for major regions.
-fn guess_timezone_from_coords(lat: f64, lon: f64) -> &'static str {
-    // North America
-    if lat >= 25.0 && lat <= 72.0 {
-        if lon >= -170.0 && lon <= -140.0 {
-            return "America/Anchorage";  // Alaska
-        } else if lon >= -125.0 && lon <= -114.0 {
-            return "America/Los_Angeles";  // Pacific
-        } else if lon >= -115.0 && lon <= -104.0 {
-            if lat >= 31.0 && lat <= 37.0 && lon >= -114.0 && lon <= -109.0 {
-                return "America/Phoenix";  // Arizona (no DST)
-            }
-            return "America/Denver";  // Mountain
-        } else if lon >= -105.0 && lon <= -87.0 {
-            return "America/Chicago";  // Central
-        } else if lon >= -88.0 && lon <= -67.0 {
-            return "America/New_York";  // Eastern
-        }
-    }
-    
-    // Europe
-    if lat >= 35.0 && lat <= 71.0 && lon >= -10.0 && lon <= 40.0 {
-        if lon >= -10.0 && lon <= 0.0 {
-            return "Europe/London";  // UK/Ireland/Portugal
-        } else if lon >= 0.0 && lon <= 15.0 {
-            return "Europe/Paris";  // Western Europe
-        } else if lon >= 15.0 && lon <= 30.0 {
-            return "Europe/Berlin";  // Central Europe
-        } else {
-            return "Europe/Moscow";  // Eastern Europe
-        }
-    }
-    
-    // Asia
-    if lat >= -10.0 && lat <= 55.0 && lon >= 60.0 && lon <= 180.0 {
-        if lon >= 60.0 && lon <= 90.0 {
-            return "Asia/Kolkata";  // India
-        } else if lon >= 100.0 && lon <= 110.0 {
-            return "Asia/Bangkok";  // SE Asia
-        } else if lon >= 115.0 && lon <= 125.0 {
-            return "Asia/Shanghai";  // China
-        } else if lon >= 135.0 && lon <= 145.0 {
-            return "Asia/Tokyo";  // Japan
-        }
-    }
-    
-    // Australia
-    if lat >= -45.0 && lat <= -10.0 && lon >= 110.0 && lon <= 155.0 {
-        if lon >= 110.0 && lon <= 130.0 {
-            return "Australia/Perth";  // Western
-        } else if lon >= 135.0 && lon <= 145.0 {
-            return "Australia/Adelaide";  // Central
-        } else {
-            return "Australia/Sydney";  // Eastern
-        }
-    }
-    
-    // South America
-    if lat >= -55.0 && lat <= 12.0 && lon >= -82.0 && lon <= -34.0 {
-        if lon >= -82.0 && lon <= -75.0 {
-            return "America/Lima";  // Peru/Colombia
-        } else if lon >= -75.0 && lon <= -50.0 {
-            return "America/Sao_Paulo";  // Brazil
-        } else {
-            return "America/Argentina/Buenos_Aires";
-        }
-    }
-    
-    // Africa
-    if lat >= -35.0 && lat <= 37.0 && lon >= -17.0 && lon <= 52.0 {
-        if lon >= 25.0 && lon <= 32.0 && lat >= -30.0 && lat <= -22.0 {
-            return "Africa/Johannesburg";  // South Africa
-        } else if lon >= 30.0 && lon <= 32.0 && lat >= 29.0 && lat <= 32.0 {
-            return "Africa/Cairo";  // Egypt
-        } else {
-            return "Africa/Lagos";  // West Africa
-        }
-    }
-    
-    // Default: UTC
-    "UTC"
-}
-
 /// Search locations using Photon API (primary, no auth needed, autocomplete-friendly)
 async fn search_location_photon(query: &str) -> Result<Vec<LocationResult>, String> {
     let url = format!(
@@ -309,6 +218,8 @@ pub fn NatalTab() -> Element {
     let mut location_search = use_signal(|| String::from(""));
     let mut latitude = use_signal(|| String::from(""));
     let mut longitude = use_signal(|| String::from(""));
+    let mut extra_bodies = use_signal(Vec::<CelestialBody>::new);
+    let mut include_fixed_stars = use_signal(|| false);
     
     // Location search state
     let mut show_results = use_signal(|| false);
@@ -417,123 +328,31 @@ pub fn NatalTab() -> Element {
                 return;
             }
             
-            // ═══════════════════════════════════════════════════════════════
-            // ✅ NEW: PROPER TIMEZONE HANDLING WITH DST SUPPORT
-            // ═══════════════════════════════════════════════════════════════
-            
-            // Parse timezone string (IANA format like "America/Los_Angeles")
-            let tz_str = timezone.read().clone();
-            let tz: Tz = match tz_str.parse() {
-                Ok(tz) => tz,
-                Err(_) => {
-                    error_message.set(format!(
-                        "Invalid timezone: {}. Please use IANA timezone format (e.g., America/Los_Angeles)",
-                        tz_str
-                    ));
-                    is_calculating.set(false);
-                    return;
-                }
-            };
-            
-            // Parse date and time components
-            let birth_date_str = birth_date.read().clone();
-            let birth_time_str = birth_time.read().clone();
-            let date_parts: Vec<&str> = birth_date_str.split('-').collect();
-            let time_parts: Vec<&str> = birth_time_str.split(':').collect();
-            
-            if date_parts.len() != 3 || time_parts.len() != 2 {
-                error_message.set("Invalid date or time format".to_string());
-                is_calculating.set(false);
-                return;
-            }
-            
-            let year = match date_parts[0].parse::<i32>() {
-                Ok(y) => y,
-                Err(_) => {
-                    error_message.set("Invalid year".to_string());
-                    is_calculating.set(false);
-                    return;
-                }
-            };
-            
-            let month = match date_parts[1].parse::<u32>() {
-                Ok(m) => m,
-                Err(_) => {
-                    error_message.set("Invalid month".to_string());
-                    is_calculating.set(false);
-                    return;
-                }
-            };
-            
-            let day = match date_parts[2].parse::<u32>() {
-                Ok(d) => d,
-                Err(_) => {
-                    error_message.set("Invalid day".to_string());
-                    is_calculating.set(false);
-                    return;
-                }
-            };
-            
-            let hour = match time_parts[0].parse::<u32>() {
-                Ok(h) => h,
-                Err(_) => {
-                    error_message.set("Invalid hour".to_string());
-                    is_calculating.set(false);
-                    return;
-                }
-            };
-            
-            let minute = match time_parts[1].parse::<u32>() {
-                Ok(m) => m,
-                Err(_) => {
-                    error_message.set("Invalid minute".to_string());
-                    is_calculating.set(false);
-                    return;
-                }
-            };
-            
-            // Build naive datetime (without timezone)
-            let naive_date = match NaiveDate::from_ymd_opt(year, month, day) {
-                Some(d) => d,
-                None => {
-                    error_message.set(format!("Invalid date: {}-{}-{}", year, month, day));
-                    is_calculating.set(false);
-                    return;
-                }
-            };
-            
-            let naive_time = match NaiveTime::from_hms_opt(hour, minute, 0) {
-                Some(t) => t,
-                None => {
-                    error_message.set(format!("Invalid time: {}:{}", hour, minute));
-                    is_calculating.set(false);
-                    return;
-                }
-            };
-            
-            let naive_dt = NaiveDateTime::new(naive_date, naive_time);
-            
-            // Convert from local time in the specified timezone to UTC
-            // This automatically handles DST! ✨
-            let local_dt = match tz.from_local_datetime(&naive_dt).single() {
-                Some(dt) => dt,
-                None => {
-                    error_message.set(format!(
-                        "Ambiguous time (DST transition). Please verify date/time: {} {}",
-                        birth_date.read(),
-                        birth_time.read()
-                    ));
+            // Local birth time in its IANA timezone to UTC (handles DST)
+            let utc_dt = match parse_local_datetime(
+                &birth_date.read(),
+                &birth_time.read(),
+                &timezone.read(),
+                "birth",
+            ) {
+                Ok(dt) => dt,
+                Err(e) => {
+                    error_message.set(e);
                     is_calculating.set(false);
                     return;
                 }
             };
             
-            let utc_dt = local_dt.with_timezone(&Utc);
-            
-            // Create chart input
-            let input = ChartInput::new(utc_dt, lat, lon)
+            // Create chart input (default bodies plus any selected extras)
+            let mut bodies = CelestialBody::all();
+            bodies.extend(extra_bodies.read().iter().copied());
+            let mut input = ChartInput::new(utc_dt, lat, lon)
                 .with_name(name.read().clone())
-                .with_gender(gender.read().clone());
+                .with_gender(gender.read().clone())
+                .with_bodies(bodies);
+            if *include_fixed_stars.read() {
+                input = input.with_fixed_stars(FixedStarOptions::new(StarList::Behenian));
+            }
             
             // Calculate chart
             match calculate_chart(&input) {
@@ -773,6 +592,41 @@ pub fn NatalTab() -> Element {
                     }
                 }
                 
+                // Additional Points
+                div { class: "form-group",
+                    label { "Additional Points" }
+                    div { class: "radio-group",
+                        for body in CelestialBody::additional().into_iter().chain(Lot::all().into_iter().map(|lot| lot.body())) {
+                            label { class: "radio-label",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: extra_bodies.read().contains(&body),
+                                    onchange: move |_| {
+                                        let mut selected = extra_bodies.write();
+                                        if let Some(index) = selected.iter().position(|b| *b == body) {
+                                            selected.remove(index);
+                                        } else {
+                                            selected.push(body);
+                                        }
+                                    }
+                                }
+                                "{body.to_string()}"
+                            }
+                        }
+                        label { class: "radio-label",
+                            input {
+                                r#type: "checkbox",
+                                checked: *include_fixed_stars.read(),
+                                onchange: move |_| {
+                                    let current = *include_fixed_stars.read();
+                                    include_fixed_stars.set(!current);
+                                }
+                            }
+                            "Fixed Stars (Behenian)"
+                        }
+                    }
+                }
+                
                 // Error Message
                 if !error_message.read().is_empty() {
                     div { class: "error-message",
@@ -794,35 +648,9 @@ pub fn NatalTab() -> Element {
             }
             
             // Right Panel - Results
-            div { class: "results-panel",
-                div { class: "results-display",
-                    if results.read().is_empty() {
-                        p { class: "results-placeholder",
-                            "Enter birth information and click Calculate Chart to see results"
-                        }
-                    } else {
-                        pre { 
-                            style: "white-space: pre-wrap; font-family: 'Courier New', monospace;",
-                            "{results}"
-                        }
-                    }
-                }
-                
-                if !results.read().is_empty() {
-                    div { class: "results-actions",
-                        button {
-                            class: "btn-secondary",
-                            onclick: move |_| {
-                                // Copy to clipboard functionality
-                                use copypasta::{ClipboardContext, ClipboardProvider};
-                                if let Ok(mut ctx) = ClipboardContext::new() {
-                                    let _ = ctx.set_contents(results.read().clone());
-                                }
-                            },
-                            "📋 Copy to Clipboard"
-                        }
-                    }
-                }
+            ResultsPanel {
+                results,
+                placeholder: "Enter birth information and click Calculate Chart to see results"
             }
         }
     }
@@ -0,0 +1,107 @@
+use dioxus::prelude::*;
+use astro_calc::{calculate_progressed_chart, format_progressed_chart, ProgressedAngleMethod};
+use super::chart_form::{use_date_fields, use_natal_fields, DateForm, NatalForm, ResultsPanel};
+
+#[component]
+pub fn ProgressionsTab() -> Element {
+    let natal = use_natal_fields("Male");
+    let target = use_date_fields();
+    let mut method = use_signal(|| ProgressedAngleMethod::Naibod);
+    
+    // Results state
+    let mut results = use_signal(|| String::from(""));
+    let mut error_message = use_signal(|| String::from(""));
+    let mut is_calculating = use_signal(|| false);
+
+    let calculate = move |_| {
+        spawn(async move {
+            is_calculating.set(true);
+            error_message.set(String::new());
+            
+            let inputs = natal
+                .to_chart_input("natal")
+                .and_then(|natal_input| Ok((natal_input, target.to_utc("progressed")?)));
+            let (natal_input, target_dt) = match inputs {
+                Ok(inputs) => inputs,
+                Err(e) => {
+                    error_message.set(e);
+                    is_calculating.set(false);
+                    return;
+                }
+            };
+            
+            // Calculate progressions
+            match calculate_progressed_chart(&natal_input, target_dt, *method.read()) {
+                Ok((natal_chart, progressed_chart, aspects)) => {
+                    let output = format_progressed_chart(&natal_chart, &progressed_chart, &aspects);
+                    results.set(output);
+                }
+                Err(e) => {
+                    error_message.set(format!("Calculation error: {}", e));
+                }
+            }
+            
+            is_calculating.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "form-container",
+            
+            // Left Panel - Input Form
+            div { class: "input-panel",
+                
+                h2 { "Natal Chart" }
+                NatalForm { fields: natal, radio_group: "gender" }
+                
+                DateForm {
+                    fields: target,
+                    label: "Progressed",
+                    hint: "Secondary progressions (day-for-a-year) to this date/time"
+                }
+                
+                div { class: "form-group",
+                    label { "Progressed Angles" }
+                    div { class: "radio-group",
+                        for option in [ProgressedAngleMethod::Naibod, ProgressedAngleMethod::SolarArc, ProgressedAngleMethod::MeanQuotidian] {
+                            label { class: "radio-label",
+                                input {
+                                    r#type: "radio",
+                                    name: "progressed-angles",
+                                    checked: *method.read() == option,
+                                    onchange: move |_| method.set(option)
+                                }
+                                "{option.to_string()}"
+                            }
+                        }
+                    }
+                }
+                
+                // Error Message
+                if !error_message.read().is_empty() {
+                    div { class: "error-message",
+                        "{error_message}"
+                    }
+                }
+                
+                // Calculate Button
+                button {
+                    class: "btn-primary",
+                    onclick: calculate,
+                    disabled: *is_calculating.read(),
+                    if *is_calculating.read() {
+                        "⏳ Calculating..."
+                    } else {
+                        "Calculate Progressions"
+                    }
+                }
+            }
+            
+            // Right Panel - Results
+            ResultsPanel {
+                results,
+                placeholder: "Enter natal chart data and progressed date, then click Calculate Progressions"
+            }
+        }
+    }
+}
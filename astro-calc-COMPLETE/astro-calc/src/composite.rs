@@ -0,0 +1,281 @@
+use crate::aspects::angular_separation;
+use crate::chart::{AnglePoint, AnglePosition, CelestialBody, Chart, HouseCusps, Position};
+use crate::house_rulers::calculate_house_rulers;
+use crate::sweph::{
+    calculate_chart, calculate_houses_from_armc, calculate_jd, jd_to_datetime, mc_to_armc,
+    ChartInput,
+};
+
+/// Greatest elongation of Mercury and Venus from the Sun, used to pick the near midpoint
+const MERCURY_MAX_ELONGATION: f64 = 28.0;
+const VENUS_MAX_ELONGATION: f64 = 48.0;
+
+/// How the houses of a midpoint composite are built
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompositeHouses {
+    #[default]
+    Derived, // Houses cast from the composite MC at the midpoint latitude
+    Midpoint, // Midpoint of each pair of cusps
+}
+
+impl CompositeHouses {
+    pub fn to_string(&self) -> &str {
+        match self {
+            CompositeHouses::Derived => "Derived from composite MC",
+            CompositeHouses::Midpoint => "Midpoint cusps",
+        }
+    }
+}
+
+/// Midpoint on the shorter arc between two longitudes
+pub fn near_midpoint(a: f64, b: f64) -> f64 {
+    let diff = (b - a).rem_euclid(360.0);
+    if diff <= 180.0 {
+        (a + diff / 2.0).rem_euclid(360.0)
+    } else {
+        (a - (360.0 - diff) / 2.0).rem_euclid(360.0)
+    }
+}
+
+/// Midpoint composite of two people
+/// Uses derived houses; see `calculate_composite_chart_with_houses` for midpoint cusps
+pub fn calculate_composite_chart(a: &ChartInput, b: &ChartInput) -> Result<Chart, String> {
+    calculate_composite_chart_with_houses(a, b, CompositeHouses::Derived)
+}
+
+/// Midpoint composite of two people with the chosen house method
+/// Bodies present in both charts get the near midpoint of their longitudes;
+/// Mercury and Venus are moved to the far midpoint if the near one would put them
+/// further from the composite Sun than they can ever be
+pub fn calculate_composite_chart_with_houses(
+    a: &ChartInput,
+    b: &ChartInput,
+    houses: CompositeHouses,
+) -> Result<Chart, String> {
+    let chart_a = calculate_chart(a)?;
+    let chart_b = calculate_chart(b)?;
+
+    let angle = |chart: &Chart, point: AnglePoint| {
+        chart
+            .get_angle(point)
+            .map(|p| p.longitude)
+            .ok_or("Chart is missing its angles".to_string())
+    };
+    let mc = near_midpoint(
+        angle(&chart_a, AnglePoint::Midheaven)?,
+        angle(&chart_b, AnglePoint::Midheaven)?,
+    );
+
+    let (cusps, asc) = match houses {
+        CompositeHouses::Derived => {
+            let jd = (calculate_jd(&a.datetime) + calculate_jd(&b.datetime)) / 2.0;
+            let latitude = (a.latitude + b.latitude) / 2.0;
+            let (cusps, asc, _, _) = calculate_houses_from_armc(jd, mc_to_armc(jd, mc)?, latitude)?;
+            (cusps, asc)
+        }
+        CompositeHouses::Midpoint => {
+            let mut asc = near_midpoint(
+                angle(&chart_a, AnglePoint::Ascendant)?,
+                angle(&chart_b, AnglePoint::Ascendant)?,
+            );
+            // The Ascendant always lies in the half of the zodiac after the MC
+            if (asc - mc).rem_euclid(360.0) > 180.0 {
+                asc = (asc + 180.0) % 360.0;
+            }
+
+            let mut cusps = [0.0; 12];
+            cusps[0] = asc;
+            cusps[9] = mc;
+            for i in [1, 2, 10, 11] {
+                let mut cusp = near_midpoint(chart_a.houses.cusps[i], chart_b.houses.cusps[i]);
+                // Keep each cusp between its neighbouring angles
+                let (from, to) = if i < 3 {
+                    (asc, (mc + 180.0) % 360.0)
+                } else {
+                    (mc, asc)
+                };
+                if (cusp - from).rem_euclid(360.0) > (to - from).rem_euclid(360.0) {
+                    cusp = (cusp + 180.0) % 360.0;
+                }
+                cusps[i] = cusp;
+            }
+            for i in [3, 4, 5] {
+                cusps[i] = (cusps[i + 6] + 180.0) % 360.0;
+            }
+            for i in [6, 7, 8] {
+                cusps[i] = (cusps[i - 6] + 180.0) % 360.0;
+            }
+            (HouseCusps::new(cusps), asc)
+        }
+    };
+
+    let name = match (&chart_a.name, &chart_b.name) {
+        (Some(name_a), Some(name_b)) => Some(format!("{} & {} Composite", name_a, name_b)),
+        _ => Some("Composite".to_string()),
+    };
+    let mut chart = Chart::new().with_metadata(name, None);
+    chart.houses = cusps;
+    chart.angles.push(AnglePosition {
+        angle: AnglePoint::Ascendant,
+        longitude: asc,
+    });
+    chart.angles.push(AnglePosition {
+        angle: AnglePoint::Midheaven,
+        longitude: mc,
+    });
+
+    for pos_a in &chart_a.positions {
+        let Some(pos_b) = chart_b.get_position(pos_a.body) else {
+            continue;
+        };
+        chart.positions.push(Position {
+            body: pos_a.body,
+            longitude: near_midpoint(pos_a.longitude, pos_b.longitude),
+            retrograde: false,
+            house: 0,
+            horizon: None,
+        });
+    }
+
+    // Mercury and Venus cannot stray far from the Sun
+    if let Some(sun) = chart.get_position(CelestialBody::Sun).map(|p| p.longitude) {
+        for pos in chart.positions.iter_mut() {
+            let limit = match pos.body {
+                CelestialBody::Mercury => MERCURY_MAX_ELONGATION,
+                CelestialBody::Venus => VENUS_MAX_ELONGATION,
+                _ => continue,
+            };
+            if angular_separation(pos.longitude, sun) > limit {
+                pos.longitude = (pos.longitude + 180.0) % 360.0;
+            }
+        }
+    }
+
+    for pos in chart.positions.iter_mut() {
+        pos.house = chart.houses.get_house(pos.longitude);
+    }
+    chart.house_rulers = Some(calculate_house_rulers(&chart));
+
+    Ok(chart)
+}
+
+/// Great-circle midpoint of several places (centroid on the sphere)
+/// Returns (latitude, longitude); fails for places that cancel out, such as antipodes
+pub fn geographic_midpoint(places: &[(f64, f64)]) -> Result<(f64, f64), String> {
+    let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+    for (latitude, longitude) in places {
+        let (lat, lon) = (latitude.to_radians(), longitude.to_radians());
+        x += lat.cos() * lon.cos();
+        y += lat.cos() * lon.sin();
+        z += lat.sin();
+    }
+
+    let horizontal = (x * x + y * y).sqrt();
+    if horizontal < 1e-9 && z.abs() < 1e-9 {
+        return Err("Places are antipodal; their midpoint is undefined".to_string());
+    }
+    Ok((z.atan2(horizontal).to_degrees(), y.atan2(x).to_degrees()))
+}
+
+/// Time and place of a Davison chart: mean Julian Day and geographic midpoint
+/// Bodies come from the first input; names are joined for the chart title
+pub fn davison_chart_input(inputs: &[ChartInput]) -> Result<ChartInput, String> {
+    if inputs.len() < 2 {
+        return Err("A Davison chart needs at least two people".to_string());
+    }
+
+    let jd = inputs
+        .iter()
+        .map(|i| calculate_jd(&i.datetime))
+        .sum::<f64>()
+        / inputs.len() as f64;
+    let places: Vec<(f64, f64)> = inputs.iter().map(|i| (i.latitude, i.longitude)).collect();
+    let (latitude, longitude) = geographic_midpoint(&places)?;
+
+    let names: Vec<&str> = inputs.iter().filter_map(|i| i.name.as_deref()).collect();
+    let name = if names.len() == inputs.len() {
+        format!("{} Davison", names.join(" & "))
+    } else {
+        "Davison".to_string()
+    };
+
+    Ok(ChartInput::new(jd_to_datetime(jd), latitude, longitude)
+        .with_name(name)
+        .with_bodies(inputs[0].bodies.clone()))
+}
+
+/// Davison relationship chart: a real chart cast for the midpoint in time and space
+pub fn calculate_davison_chart(inputs: &[ChartInput]) -> Result<Chart, String> {
+    calculate_chart(&davison_chart_input(inputs)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aspects::calculate_aspects;
+    use crate::sweph::init_sweph;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_composite_midpoints_and_houses() {
+        init_sweph();
+        let a = ChartInput::new(
+            Utc.with_ymd_and_hms(1985, 3, 10, 8, 15, 0).unwrap(),
+            51.5074,
+            -0.1278,
+        );
+        let b = ChartInput::new(
+            Utc.with_ymd_and_hms(1987, 9, 22, 19, 40, 0).unwrap(),
+            48.8566,
+            2.3522,
+        );
+
+        assert!((near_midpoint(350.0, 10.0) - 0.0).abs() < 1e-9);
+        assert!((near_midpoint(10.0, 350.0) - 0.0).abs() < 1e-9);
+
+        for houses in [CompositeHouses::Derived, CompositeHouses::Midpoint] {
+            let chart = calculate_composite_chart_with_houses(&a, &b, houses).unwrap();
+            let sun = chart.get_position(CelestialBody::Sun).unwrap().longitude;
+            let mercury = chart
+                .get_position(CelestialBody::Mercury)
+                .unwrap()
+                .longitude;
+            let venus = chart.get_position(CelestialBody::Venus).unwrap().longitude;
+            assert!(angular_separation(sun, mercury) <= MERCURY_MAX_ELONGATION);
+            assert!(angular_separation(sun, venus) <= VENUS_MAX_ELONGATION);
+
+            let asc = chart.get_angle(AnglePoint::Ascendant).unwrap().longitude;
+            let mc = chart.get_angle(AnglePoint::Midheaven).unwrap().longitude;
+            assert!((asc - mc).rem_euclid(360.0) < 180.0);
+            assert!(chart.positions.iter().all(|p| (1..=12).contains(&p.house)));
+            assert!(!calculate_aspects(&chart).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_davison_midpoint() {
+        init_sweph();
+        // London and New York: the great-circle midpoint lies far north of both
+        let a = ChartInput::new(
+            Utc.with_ymd_and_hms(1981, 1, 1, 0, 0, 0).unwrap(),
+            51.5074,
+            -0.1278,
+        );
+        let b = ChartInput::new(
+            Utc.with_ymd_and_hms(1983, 1, 1, 0, 0, 0).unwrap(),
+            40.7128,
+            -74.0060,
+        );
+        let input = davison_chart_input(&[a.clone(), b.clone()]).unwrap();
+        assert_eq!(
+            input.datetime,
+            Utc.with_ymd_and_hms(1982, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert!(input.latitude > 51.5 && input.latitude < 53.0);
+        assert!(input.longitude > -42.0 && input.longitude < -41.0);
+
+        let chart = calculate_davison_chart(&[a.clone(), b, a]).unwrap();
+        assert!(chart.sect.is_some());
+        assert!(davison_chart_input(&[ChartInput::new(input.datetime, 0.0, 0.0)]).is_err());
+    }
+}
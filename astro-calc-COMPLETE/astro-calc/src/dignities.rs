@@ -0,0 +1,446 @@
+use crate::chart::{AnglePoint, CelestialBody, Chart, Element, Sect, ZodiacSign};
+
+/// The seven planets that hold essential dignities
+pub const CLASSICAL_PLANETS: [CelestialBody; 7] = [
+    CelestialBody::Sun,
+    CelestialBody::Moon,
+    CelestialBody::Mercury,
+    CelestialBody::Venus,
+    CelestialBody::Mars,
+    CelestialBody::Jupiter,
+    CelestialBody::Saturn,
+];
+
+/// Descending order of the planetary spheres, used for the faces
+const CHALDEAN_FACE_ORDER: [CelestialBody; 7] = [
+    CelestialBody::Mars,
+    CelestialBody::Sun,
+    CelestialBody::Venus,
+    CelestialBody::Mercury,
+    CelestialBody::Moon,
+    CelestialBody::Saturn,
+    CelestialBody::Jupiter,
+];
+
+/// Egyptian terms: (ruler, end degree within the sign) for each sign from Aries
+const EGYPTIAN_TERMS: [[(CelestialBody, f64); 5]; 12] = {
+    use CelestialBody::{Jupiter as J, Mars as Ma, Mercury as Me, Saturn as S, Venus as V};
+    [
+        [(J, 6.0), (V, 12.0), (Me, 20.0), (Ma, 25.0), (S, 30.0)],
+        [(V, 8.0), (Me, 14.0), (J, 22.0), (S, 27.0), (Ma, 30.0)],
+        [(Me, 6.0), (J, 12.0), (V, 17.0), (Ma, 24.0), (S, 30.0)],
+        [(Ma, 7.0), (V, 13.0), (Me, 19.0), (J, 26.0), (S, 30.0)],
+        [(J, 6.0), (V, 11.0), (S, 18.0), (Me, 24.0), (Ma, 30.0)],
+        [(Me, 7.0), (V, 17.0), (J, 21.0), (Ma, 28.0), (S, 30.0)],
+        [(S, 6.0), (Me, 14.0), (J, 21.0), (V, 28.0), (Ma, 30.0)],
+        [(Ma, 7.0), (V, 11.0), (Me, 19.0), (J, 24.0), (S, 30.0)],
+        [(J, 12.0), (V, 17.0), (Me, 21.0), (S, 26.0), (Ma, 30.0)],
+        [(Me, 7.0), (J, 14.0), (V, 22.0), (S, 26.0), (Ma, 30.0)],
+        [(Me, 7.0), (V, 13.0), (J, 20.0), (Ma, 25.0), (S, 30.0)],
+        [(V, 12.0), (J, 16.0), (Me, 19.0), (Ma, 28.0), (S, 30.0)],
+    ]
+};
+
+/// Which set of sign rulers to use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rulership {
+    #[default]
+    Traditional, // Seven visible planets only
+    Modern, // Uranus, Neptune and Pluto rule Aquarius, Pisces and Scorpio
+}
+
+impl Rulership {
+    pub fn to_string(&self) -> &str {
+        match self {
+            Rulership::Traditional => "Traditional",
+            Rulership::Modern => "Modern",
+        }
+    }
+
+    /// Domicile rulers of a sign, main ruler first
+    /// Under modern rulership the traditional ruler stays on as co-ruler
+    pub fn rulers(&self, sign: ZodiacSign) -> Vec<CelestialBody> {
+        let traditional = sign.traditional_ruler();
+        match self {
+            Rulership::Modern if sign.modern_ruler() != traditional => {
+                vec![sign.modern_ruler(), traditional]
+            }
+            _ => vec![traditional],
+        }
+    }
+
+    /// Planets that receive essential dignities under this scheme
+    pub fn planets(&self) -> Vec<CelestialBody> {
+        let mut planets = CLASSICAL_PLANETS.to_vec();
+        if *self == Rulership::Modern {
+            planets.extend([
+                CelestialBody::Uranus,
+                CelestialBody::Neptune,
+                CelestialBody::Pluto,
+            ]);
+        }
+        planets
+    }
+}
+
+/// Table of triplicity rulers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TriplicityScheme {
+    #[default]
+    Dorothean, // Day, night and participating rulers
+    Lilly, // Day and night rulers only; Mars rules the water signs by day and night
+}
+
+impl TriplicityScheme {
+    pub fn to_string(&self) -> &str {
+        match self {
+            TriplicityScheme::Dorothean => "Dorothean",
+            TriplicityScheme::Lilly => "Lilly",
+        }
+    }
+
+    /// (day ruler, night ruler, participating ruler) of an element
+    pub fn rulers(
+        &self,
+        element: Element,
+    ) -> (CelestialBody, CelestialBody, Option<CelestialBody>) {
+        use CelestialBody::*;
+        match (self, element) {
+            (TriplicityScheme::Dorothean, Element::Fire) => (Sun, Jupiter, Some(Saturn)),
+            (TriplicityScheme::Dorothean, Element::Earth) => (Venus, Moon, Some(Mars)),
+            (TriplicityScheme::Dorothean, Element::Air) => (Saturn, Mercury, Some(Jupiter)),
+            (TriplicityScheme::Dorothean, Element::Water) => (Venus, Mars, Some(Moon)),
+            (TriplicityScheme::Lilly, Element::Fire) => (Sun, Jupiter, None),
+            (TriplicityScheme::Lilly, Element::Earth) => (Venus, Moon, None),
+            (TriplicityScheme::Lilly, Element::Air) => (Saturn, Mercury, None),
+            (TriplicityScheme::Lilly, Element::Water) => (Mars, Mars, None),
+        }
+    }
+
+    /// Triplicity ruler in charge for the chart's sect
+    pub fn ruler(&self, sign: ZodiacSign, sect: Sect) -> CelestialBody {
+        let (day, night, _) = self.rulers(sign.element());
+        match sect {
+            Sect::Day => day,
+            Sect::Night => night,
+        }
+    }
+}
+
+/// Essential dignity or debility with its Lilly point value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dignity {
+    Domicile,
+    Exaltation,
+    Triplicity,
+    Term,
+    Face,
+    Detriment,
+    Fall,
+    Peregrine, // No dignity of its own where it stands
+}
+
+impl Dignity {
+    pub fn to_string(&self) -> &str {
+        match self {
+            Dignity::Domicile => "Domicile",
+            Dignity::Exaltation => "Exaltation",
+            Dignity::Triplicity => "Triplicity",
+            Dignity::Term => "Term",
+            Dignity::Face => "Face",
+            Dignity::Detriment => "Detriment",
+            Dignity::Fall => "Fall",
+            Dignity::Peregrine => "Peregrine",
+        }
+    }
+
+    pub fn score(&self) -> i32 {
+        match self {
+            Dignity::Domicile => 5,
+            Dignity::Exaltation => 4,
+            Dignity::Triplicity => 3,
+            Dignity::Term => 2,
+            Dignity::Face => 1,
+            Dignity::Detriment => -5,
+            Dignity::Fall => -4,
+            Dignity::Peregrine => -5,
+        }
+    }
+}
+
+/// Planet exalted in a sign, if any
+pub fn exaltation_ruler(sign: ZodiacSign) -> Option<CelestialBody> {
+    match sign {
+        ZodiacSign::Aries => Some(CelestialBody::Sun),
+        ZodiacSign::Taurus => Some(CelestialBody::Moon),
+        ZodiacSign::Cancer => Some(CelestialBody::Jupiter),
+        ZodiacSign::Virgo => Some(CelestialBody::Mercury),
+        ZodiacSign::Libra => Some(CelestialBody::Saturn),
+        ZodiacSign::Capricorn => Some(CelestialBody::Mars),
+        ZodiacSign::Pisces => Some(CelestialBody::Venus),
+        _ => None,
+    }
+}
+
+/// Planets in detriment in a sign (those ruling the opposite sign)
+pub fn detriment_rulers(sign: ZodiacSign, rulership: Rulership) -> Vec<CelestialBody> {
+    rulership.rulers(sign.opposite())
+}
+
+/// Planet in fall in a sign (the one exalted in the opposite sign)
+pub fn fall_ruler(sign: ZodiacSign) -> Option<CelestialBody> {
+    exaltation_ruler(sign.opposite())
+}
+
+/// Egyptian term ruler of a longitude
+pub fn term_ruler(longitude: f64) -> CelestialBody {
+    let longitude = longitude.rem_euclid(360.0);
+    let degree = longitude % 30.0;
+    EGYPTIAN_TERMS[ZodiacSign::from_longitude(longitude).index()]
+        .iter()
+        .find(|(_, end)| degree < *end)
+        .map(|(ruler, _)| *ruler)
+        .unwrap_or(CelestialBody::Saturn)
+}
+
+/// Chaldean face (decan) ruler of a longitude
+pub fn face_ruler(longitude: f64) -> CelestialBody {
+    let decan = (longitude.rem_euclid(360.0) / 10.0).floor() as usize % 36;
+    CHALDEAN_FACE_ORDER[decan % 7]
+}
+
+/// Dignity options: which rulers and triplicity table to use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DignityOptions {
+    pub rulership: Rulership,
+    pub triplicity: TriplicityScheme,
+}
+
+impl DignityOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rulership(mut self, rulership: Rulership) -> Self {
+        self.rulership = rulership;
+        self
+    }
+
+    pub fn with_triplicity(mut self, triplicity: TriplicityScheme) -> Self {
+        self.triplicity = triplicity;
+        self
+    }
+}
+
+/// Essential dignities and debilities of a planet at a longitude
+/// Peregrine applies to the seven classical planets only
+pub fn essential_dignities(
+    body: CelestialBody,
+    longitude: f64,
+    sect: Sect,
+    options: &DignityOptions,
+) -> Vec<Dignity> {
+    let sign = ZodiacSign::from_longitude(longitude.rem_euclid(360.0));
+    let mut dignities = Vec::new();
+
+    if options.rulership.rulers(sign).contains(&body) {
+        dignities.push(Dignity::Domicile);
+    }
+    if exaltation_ruler(sign) == Some(body) {
+        dignities.push(Dignity::Exaltation);
+    }
+    if options.triplicity.ruler(sign, sect) == body {
+        dignities.push(Dignity::Triplicity);
+    }
+    if term_ruler(longitude) == body {
+        dignities.push(Dignity::Term);
+    }
+    if face_ruler(longitude) == body {
+        dignities.push(Dignity::Face);
+    }
+    if dignities.is_empty() && CLASSICAL_PLANETS.contains(&body) {
+        dignities.push(Dignity::Peregrine);
+    }
+
+    if detriment_rulers(sign, options.rulership).contains(&body) {
+        dignities.push(Dignity::Detriment);
+    }
+    if fall_ruler(sign) == Some(body) {
+        dignities.push(Dignity::Fall);
+    }
+    dignities
+}
+
+/// Points a classical planet holds at a longitude (domicile 5 down to face 1)
+fn dignity_points(
+    body: CelestialBody,
+    longitude: f64,
+    sect: Sect,
+    triplicity: TriplicityScheme,
+) -> i32 {
+    let options = DignityOptions::new().with_triplicity(triplicity);
+    essential_dignities(body, longitude, sect, &options)
+        .iter()
+        .map(|d| d.score())
+        .filter(|score| *score > 0)
+        .sum()
+}
+
+/// Almuten of a degree: the classical planet with most essential dignity there
+/// Ties go to the earlier planet in the Sun..Saturn order
+pub fn almuten(longitude: f64, sect: Sect, triplicity: TriplicityScheme) -> CelestialBody {
+    almuten_of(&[longitude], sect, triplicity)
+}
+
+/// Almuten over several degrees, summing each planet's points
+fn almuten_of(longitudes: &[f64], sect: Sect, triplicity: TriplicityScheme) -> CelestialBody {
+    let mut best = (CelestialBody::Sun, i32::MIN);
+    for planet in CLASSICAL_PLANETS {
+        let points = longitudes
+            .iter()
+            .map(|lon| dignity_points(planet, *lon, sect, triplicity))
+            .sum();
+        if points > best.1 {
+            best = (planet, points);
+        }
+    }
+    best.0
+}
+
+/// Dignities of one planet in a chart
+#[derive(Debug, Clone)]
+pub struct PlanetDignities {
+    pub body: CelestialBody,
+    pub longitude: f64,
+    pub sign: ZodiacSign,
+    pub dignities: Vec<Dignity>,
+    pub score: i32,
+}
+
+/// Almuten of a house cusp
+#[derive(Debug, Clone)]
+pub struct CuspAlmuten {
+    pub house: usize,
+    pub longitude: f64,
+    pub almuten: CelestialBody,
+}
+
+#[derive(Debug, Clone)]
+pub struct DignityReport {
+    pub options: DignityOptions,
+    pub sect: Sect,
+    pub planets: Vec<PlanetDignities>,
+    pub chart_almuten: CelestialBody,
+    pub cusp_almutens: Vec<CuspAlmuten>,
+}
+
+/// Essential dignities of every planet, the almuten of the chart and of each cusp
+/// Charts without a sect are scored as day charts. The chart almuten sums the
+/// dignities over the Sun, Moon, Ascendant, Midheaven and Part of Fortune
+pub fn calculate_dignities(chart: &Chart, options: &DignityOptions) -> DignityReport {
+    let sect = chart.sect.unwrap_or(Sect::Day);
+
+    let planets = options
+        .rulership
+        .planets()
+        .into_iter()
+        .filter_map(|body| chart.get_position(body))
+        .map(|pos| {
+            let dignities = essential_dignities(pos.body, pos.longitude, sect, options);
+            PlanetDignities {
+                body: pos.body,
+                longitude: pos.longitude,
+                sign: ZodiacSign::from_longitude(pos.longitude),
+                score: dignities.iter().map(|d| d.score()).sum(),
+                dignities,
+            }
+        })
+        .collect();
+
+    let mut hylegical: Vec<f64> = [
+        CelestialBody::Sun,
+        CelestialBody::Moon,
+        CelestialBody::Fortuna,
+    ]
+    .into_iter()
+    .filter_map(|body| chart.get_position(body).map(|p| p.longitude))
+    .collect();
+    hylegical.extend(
+        [AnglePoint::Ascendant, AnglePoint::Midheaven]
+            .into_iter()
+            .filter_map(|angle| chart.get_angle(angle).map(|a| a.longitude)),
+    );
+
+    let cusp_almutens = chart
+        .houses
+        .cusps
+        .iter()
+        .enumerate()
+        .map(|(i, cusp)| CuspAlmuten {
+            house: i + 1,
+            longitude: *cusp,
+            almuten: almuten(*cusp, sect, options.triplicity),
+        })
+        .collect();
+
+    DignityReport {
+        options: *options,
+        sect,
+        planets,
+        chart_almuten: almuten_of(&hylegical, sect, options.triplicity),
+        cusp_almutens,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::test_position as position;
+
+    #[test]
+    fn test_dignity_scores_and_almuten() {
+        assert_eq!(term_ruler(5.0), CelestialBody::Jupiter);
+        assert_eq!(term_ruler(29.9), CelestialBody::Saturn);
+        assert_eq!(face_ruler(0.0), CelestialBody::Mars);
+        assert_eq!(face_ruler(355.0), CelestialBody::Mars); // The cycle closes where it began
+
+        let mut chart = Chart::new();
+        chart.sect = Some(Sect::Day);
+        chart.positions = vec![
+            position(CelestialBody::Sun, 125.0), // 5° Leo: domicile and day triplicity
+            position(CelestialBody::Venus, 185.0), // 5° Libra: domicile
+            position(CelestialBody::Mars, 195.0), // 15° Libra: detriment, peregrine
+            position(CelestialBody::Pluto, 220.0),
+        ];
+
+        let report = calculate_dignities(&chart, &DignityOptions::new());
+        assert_eq!(report.planets.len(), 3);
+        let sun = &report.planets[0];
+        assert_eq!(sun.dignities, vec![Dignity::Domicile, Dignity::Triplicity]);
+        assert_eq!(sun.score, 8);
+        let mars = &report.planets[2];
+        assert_eq!(mars.dignities, vec![Dignity::Peregrine, Dignity::Detriment]);
+        assert_eq!(mars.score, -10);
+        assert_eq!(report.cusp_almutens.len(), 12);
+        assert_eq!(report.chart_almuten, CelestialBody::Sun);
+
+        let modern = DignityOptions::new().with_rulership(Rulership::Modern);
+        let report = calculate_dignities(&chart, &modern);
+        let pluto = report.planets.last().unwrap();
+        assert_eq!(pluto.dignities, vec![Dignity::Domicile]);
+
+        // Dorothean water signs go to Venus by day, Lilly's to Mars
+        assert_eq!(
+            TriplicityScheme::Dorothean.ruler(ZodiacSign::Cancer, Sect::Day),
+            CelestialBody::Venus
+        );
+        assert_eq!(
+            TriplicityScheme::Lilly.ruler(ZodiacSign::Cancer, Sect::Day),
+            CelestialBody::Mars
+        );
+        assert_eq!(
+            almuten(215.0, Sect::Night, TriplicityScheme::Lilly),
+            CelestialBody::Mars
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use crate::aspects::{calculate_cross_aspects, Aspect, AspectEntity, AspectType};
+use crate::chart::{AnglePoint, AnglePosition, CelestialBody, Chart, Position};
+use crate::progressions::{progressed_jd, NAIBOD_RATE, TROPICAL_YEAR_DAYS};
+use crate::sweph::{
+    calculate_armc, calculate_chart, calculate_houses_from_armc, calculate_jd, calculate_planet,
+    east_longitude, jd_to_datetime, ChartInput,
+};
+use chrono::{DateTime, Utc};
+
+/// Orb for directed-to-natal aspects
+pub const DIRECTED_ASPECT_ORB: f64 = 1.0;
+
+/// Aspects reported for directions (Ebertin-style hard aspects)
+pub const HARD_ASPECTS: [AspectType; 5] = [
+    AspectType::Conjunction,
+    AspectType::SemiSquare,
+    AspectType::Square,
+    AspectType::Sesquiquadrate,
+    AspectType::Opposition,
+];
+
+/// How far every natal point is advanced
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DirectionMethod {
+    SolarArc,     // Secondary progressed Sun minus natal Sun
+    Naibod,       // 0°59'08" per year
+    OneDegree,    // 1° per year
+    AscendantArc, // Motion of the Naibod-progressed Ascendant
+}
+
+impl DirectionMethod {
+    pub fn to_string(&self) -> &str {
+        match self {
+            DirectionMethod::SolarArc => "Solar Arc",
+            DirectionMethod::Naibod => "Naibod",
+            DirectionMethod::OneDegree => "One Degree",
+            DirectionMethod::AscendantArc => "Ascendant Arc",
+        }
+    }
+}
+
+/// A direction becoming exact
+#[derive(Debug, Clone)]
+pub struct DirectionPerfection {
+    pub directed: AspectEntity,
+    pub natal: AspectEntity,
+    pub aspect_type: AspectType,
+    pub datetime: DateTime<Utc>,
+    pub arc: f64, // Arc of direction at perfection
+}
+
+/// Natal values the arc of direction is measured from
+struct ArcContext {
+    natal_jd: f64,
+    latitude: f64,
+    natal_sun: f64,
+    natal_asc: f64,
+    natal_armc: f64,
+    method: DirectionMethod,
+}
+
+impl ArcContext {
+    fn new(
+        natal: &ChartInput,
+        natal_chart: &Chart,
+        method: DirectionMethod,
+    ) -> Result<Self, String> {
+        let natal_jd = calculate_jd(&natal.datetime);
+        Ok(Self {
+            natal_jd,
+            latitude: natal.latitude,
+            natal_sun: calculate_planet(natal_jd, CelestialBody::Sun)?.0,
+            natal_asc: natal_chart
+                .get_angle(AnglePoint::Ascendant)
+                .map(|a| a.longitude)
+                .ok_or("Natal chart has no AC")?,
+            natal_armc: calculate_armc(natal_jd, natal.latitude, east_longitude(natal.longitude))?,
+            method,
+        })
+    }
+
+    /// Arc of direction (degrees) for a target Julian Day
+    fn arc_at(&self, target_jd: f64) -> Result<f64, String> {
+        let age_years = (target_jd - self.natal_jd) / TROPICAL_YEAR_DAYS;
+        match self.method {
+            DirectionMethod::Naibod => Ok(age_years * NAIBOD_RATE),
+            DirectionMethod::OneDegree => Ok(age_years),
+            DirectionMethod::SolarArc => {
+                let progressed_sun =
+                    calculate_planet(progressed_jd(self.natal_jd, target_jd), CelestialBody::Sun)?
+                        .0;
+                Ok(unwrap_arc(progressed_sun - self.natal_sun, age_years))
+            }
+            DirectionMethod::AscendantArc => {
+                let prog_jd = progressed_jd(self.natal_jd, target_jd);
+                let armc = (self.natal_armc + age_years * NAIBOD_RATE).rem_euclid(360.0);
+                let (_, asc, _, _) = calculate_houses_from_armc(prog_jd, armc, self.latitude)?;
+                Ok(unwrap_arc(asc - self.natal_asc, age_years))
+            }
+        }
+    }
+}
+
+/// Express a longitude difference as a signed arc (negative before birth)
+fn unwrap_arc(difference: f64, age_years: f64) -> f64 {
+    let arc = difference.rem_euclid(360.0);
+    if age_years < 0.0 && arc > 0.0 {
+        arc - 360.0
+    } else {
+        arc
+    }
+}
+
+/// Calculate a directed chart for `target`: every natal position and angle advanced by the arc
+/// Returns (directed_chart, directed-to-natal hard aspects within DIRECTED_ASPECT_ORB)
+/// Directed positions carry the natal house they fall in
+pub fn calculate_directed_chart(
+    natal: &ChartInput,
+    target: DateTime<Utc>,
+    method: DirectionMethod,
+) -> Result<(Chart, Vec<Aspect>), String> {
+    let natal_chart = calculate_chart(natal)?;
+    let arc = ArcContext::new(natal, &natal_chart, method)?.arc_at(calculate_jd(&target))?;
+
+    let mut directed = Chart::new().with_metadata(natal.name.clone(), natal.gender.clone());
+    directed.houses = natal_chart.houses.clone();
+
+    for pos in &natal_chart.positions {
+        let longitude = (pos.longitude + arc).rem_euclid(360.0);
+        directed.positions.push(Position {
+            body: pos.body,
+            longitude,
+            retrograde: false, // A directed point moves with the arc, never backwards
+            house: natal_chart.houses.get_house(longitude),
+            horizon: None,
+        });
+    }
+    for angle_pos in &natal_chart.angles {
+        directed.angles.push(AnglePosition {
+            angle: angle_pos.angle,
+            longitude: (angle_pos.longitude + arc).rem_euclid(360.0),
+        });
+    }
+
+    let aspects = calculate_cross_aspects(&directed, &natal_chart, DIRECTED_ASPECT_ORB)
+        .into_iter()
+        .filter(|a| HARD_ASPECTS.contains(&a.aspect_type))
+        .collect();
+
+    Ok((directed, aspects))
+}
+
+/// Find when each directed-to-natal hard aspect perfects between `start` and `end`
+/// Results are sorted chronologically
+pub fn search_directions(
+    natal: &ChartInput,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    method: DirectionMethod,
+) -> Result<Vec<DirectionPerfection>, String> {
+    let natal_chart = calculate_chart(natal)?;
+    let context = ArcContext::new(natal, &natal_chart, method)?;
+
+    let start_jd = calculate_jd(&start);
+    let end_jd = calculate_jd(&end);
+    if end_jd <= start_jd {
+        return Err("Search end must be after start".to_string());
+    }
+    let arc_start = context.arc_at(start_jd)?;
+    let arc_end = context.arc_at(end_jd)?;
+
+    let points = natal_chart.points();
+    let mut perfections = Vec::new();
+
+    for (directed_entity, directed_lon) in &points {
+        for (natal_entity, natal_lon) in &points {
+            for aspect_type in HARD_ASPECTS {
+                // Both sides of the aspect (e.g. waxing and waning square)
+                let mut targets = vec![aspect_type.angle()];
+                if aspect_type.angle() > 0.0 && aspect_type.angle() < 180.0 {
+                    targets.push(360.0 - aspect_type.angle());
+                }
+
+                for angle in targets {
+                    // Arc needed, repeated every full circle
+                    let mut required = (natal_lon + angle - directed_lon).rem_euclid(360.0);
+                    while required > arc_start - 360.0 && required - 360.0 >= arc_start {
+                        required -= 360.0;
+                    }
+                    while required <= arc_end {
+                        if required >= arc_start {
+                            let jd = solve_arc(&context, required, start_jd, end_jd)?;
+                            perfections.push(DirectionPerfection {
+                                directed: directed_entity.clone(),
+                                natal: natal_entity.clone(),
+                                aspect_type,
+                                datetime: jd_to_datetime(jd),
+                                arc: required,
+                            });
+                        }
+                        required += 360.0;
+                    }
+                }
+            }
+        }
+    }
+
+    perfections.sort_by_key(|p| p.datetime);
+    Ok(perfections)
+}
+
+/// Bisect for the moment the (monotonic) arc of direction reaches `required`
+fn solve_arc(
+    context: &ArcContext,
+    required: f64,
+    start_jd: f64,
+    end_jd: f64,
+) -> Result<f64, String> {
+    let (mut low, mut high) = (start_jd, end_jd);
+    for _ in 0..50 {
+        let mid = (low + high) / 2.0;
+        if context.arc_at(mid)? < required {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Ok((low + high) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sweph::init_sweph;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_one_degree_direction_search() {
+        init_sweph();
+        let birth = Utc.with_ymd_and_hms(1970, 1, 1, 12, 0, 0).unwrap();
+        let input = ChartInput::new(birth, 48.8566, 2.3522)
+            .with_bodies(vec![CelestialBody::Sun, CelestialBody::Moon]);
+
+        let start = Utc.with_ymd_and_hms(1971, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2070, 1, 1, 0, 0, 0).unwrap();
+        let perfections =
+            search_directions(&input, start, end, DirectionMethod::OneDegree).unwrap();
+
+        // The directed Sun squares its own natal place after 90 years of one-degree arc
+        let sun = AspectEntity::Body(CelestialBody::Sun);
+        let square = perfections
+            .iter()
+            .find(|p| p.directed == sun && p.natal == sun && p.aspect_type == AspectType::Square)
+            .unwrap();
+        assert!((square.arc - 90.0).abs() < 1e-6);
+        let expected_jd = calculate_jd(&birth) + 90.0 * TROPICAL_YEAR_DAYS;
+        assert!((calculate_jd(&square.datetime) - expected_jd).abs() < 0.001);
+
+        // Chronological order
+        assert!(perfections
+            .windows(2)
+            .all(|w| w[0].datetime <= w[1].datetime));
+
+        // Directed points move with the arc, so none keeps a natal retrograde flag
+        let (directed, _) =
+            calculate_directed_chart(&input, end, DirectionMethod::OneDegree).unwrap();
+        assert!(directed.positions.iter().all(|p| !p.retrograde));
+    }
+}
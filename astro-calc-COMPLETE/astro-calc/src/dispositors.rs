@@ -0,0 +1,309 @@
+use crate::chart::{CelestialBody, Chart, ZodiacSign};
+use crate::dignities::{exaltation_ruler, Rulership};
+use std::fmt;
+
+/// A planet and the planet ruling the sign it stands in
+#[derive(Debug, Clone)]
+pub struct DispositorNode {
+    pub body: CelestialBody,
+    pub sign: ZodiacSign,
+    /// First ruler of the sign present in the chart (a modern ruler falls back
+    /// to its traditional co-ruler when the outer planet is not in the chart)
+    pub dispositor: Option<CelestialBody>,
+}
+
+/// Where a dispositor chain comes to rest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainEnd {
+    Final(CelestialBody),      // A planet in its own sign
+    Cycle(Vec<CelestialBody>), // Planets disposing each other in a ring, first in chart order
+    Broken(CelestialBody),     // A planet whose ruler is not in the chart
+}
+
+impl fmt::Display for ChainEnd {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChainEnd::Final(body) => write!(f, "{} (final dispositor)", body),
+            ChainEnd::Cycle(bodies) => {
+                let ring = bodies
+                    .iter()
+                    .chain(bodies.first())
+                    .map(|b| b.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                if bodies.len() == 2 {
+                    write!(f, "{} (mutual reception)", ring)
+                } else {
+                    write!(f, "{} (cycle)", ring)
+                }
+            }
+            ChainEnd::Broken(body) => write!(f, "{} (ruler not in chart)", body),
+        }
+    }
+}
+
+/// Dispositors followed from one planet until they repeat
+#[derive(Debug, Clone)]
+pub struct DispositorChain {
+    pub start: CelestialBody,
+    pub chain: Vec<CelestialBody>, // Starting planet first, each followed by its dispositor
+    pub end: ChainEnd,
+}
+
+/// How two planets receive each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReceptionKind {
+    Domicile,   // Each in a sign the other rules
+    Exaltation, // Each in the other's exaltation
+    Mixed,      // One by domicile, the other by exaltation
+}
+
+impl ReceptionKind {
+    pub fn to_string(&self) -> &str {
+        match self {
+            ReceptionKind::Domicile => "domicile",
+            ReceptionKind::Exaltation => "exaltation",
+            ReceptionKind::Mixed => "mixed",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MutualReception {
+    pub first: CelestialBody,
+    pub second: CelestialBody,
+    pub kind: ReceptionKind,
+}
+
+#[derive(Debug, Clone)]
+pub struct DispositorReport {
+    pub rulership: Rulership,
+    pub nodes: Vec<DispositorNode>,
+    pub chains: Vec<DispositorChain>,
+    pub final_dispositor: Option<CelestialBody>, // Set when every chain ends in the same planet
+    pub receptions: Vec<MutualReception>,
+}
+
+impl DispositorReport {
+    pub fn dispositor_of(&self, body: CelestialBody) -> Option<CelestialBody> {
+        self.nodes
+            .iter()
+            .find(|n| n.body == body)
+            .and_then(|n| n.dispositor)
+    }
+
+    /// Planets disposed of by `body`, leaving out `body` itself
+    pub fn disposed_by(&self, body: CelestialBody) -> Vec<CelestialBody> {
+        self.nodes
+            .iter()
+            .filter(|n| n.dispositor == Some(body) && n.body != body)
+            .map(|n| n.body)
+            .collect()
+    }
+
+    /// Graphviz digraph: an edge from each planet to its dispositor, final
+    /// dispositors double-circled and mutual receptions as dashed edges
+    pub fn to_dot(&self) -> String {
+        let mut lines = vec![
+            "digraph dispositors {".to_string(),
+            "    rankdir=BT;".to_string(),
+            "    node [shape=circle];".to_string(),
+        ];
+        for node in &self.nodes {
+            match node.dispositor {
+                Some(dispositor) if dispositor == node.body => lines.push(format!(
+                    "    \"{}\" [shape=doublecircle];",
+                    node.body
+                )),
+                Some(dispositor) => lines.push(format!(
+                    "    \"{}\" -> \"{}\";",
+                    node.body,
+                    dispositor
+                )),
+                None => lines.push(format!("    \"{}\";", node.body)),
+            }
+        }
+        for reception in &self.receptions {
+            lines.push(format!(
+                "    \"{}\" -> \"{}\" [style=dashed, dir=both, constraint=false, label=\"{}\"];",
+                reception.first,
+                reception.second,
+                reception.kind.to_string()
+            ));
+        }
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+/// Follow dispositors from `start` until a planet repeats or a ruler is missing
+fn follow_chain(nodes: &[DispositorNode], start: CelestialBody) -> DispositorChain {
+    let mut chain = vec![start];
+    let end = loop {
+        let current = *chain.last().unwrap();
+        let Some(next) = nodes
+            .iter()
+            .find(|n| n.body == current)
+            .and_then(|n| n.dispositor)
+        else {
+            break ChainEnd::Broken(current);
+        };
+        if next == current {
+            break ChainEnd::Final(current);
+        }
+        if let Some(i) = chain.iter().position(|b| *b == next) {
+            // Start the ring at its first planet in chart order so every chain reports it alike
+            let mut ring = chain[i..].to_vec();
+            let first = (0..ring.len())
+                .min_by_key(|j| nodes.iter().position(|n| n.body == ring[*j]))
+                .unwrap_or(0);
+            ring.rotate_left(first);
+            break ChainEnd::Cycle(ring);
+        }
+        chain.push(next);
+    };
+    DispositorChain { start, chain, end }
+}
+
+/// Mutual reception between two planets, if any
+fn reception(
+    a: &DispositorNode,
+    b: &DispositorNode,
+    rulership: Rulership,
+) -> Option<ReceptionKind> {
+    let by_domicile =
+        |x: &DispositorNode, y: &DispositorNode| rulership.rulers(x.sign).contains(&y.body);
+    let by_exaltation =
+        |x: &DispositorNode, y: &DispositorNode| exaltation_ruler(x.sign) == Some(y.body);
+
+    if by_domicile(a, b) && by_domicile(b, a) {
+        Some(ReceptionKind::Domicile)
+    } else if by_exaltation(a, b) && by_exaltation(b, a) {
+        Some(ReceptionKind::Exaltation)
+    } else if (by_domicile(a, b) && by_exaltation(b, a))
+        || (by_exaltation(a, b) && by_domicile(b, a))
+    {
+        Some(ReceptionKind::Mixed)
+    } else {
+        None
+    }
+}
+
+/// Dispositor chains, final dispositor and mutual receptions of a chart
+/// Covers the seven classical planets, plus Uranus, Neptune and Pluto under modern rulership
+pub fn calculate_dispositors(chart: &Chart, rulership: Rulership) -> DispositorReport {
+    let planets: Vec<CelestialBody> = rulership
+        .planets()
+        .into_iter()
+        .filter(|body| chart.get_position(*body).is_some())
+        .collect();
+
+    let nodes: Vec<DispositorNode> = planets
+        .iter()
+        .filter_map(|body| chart.get_position(*body))
+        .map(|pos| {
+            let sign = ZodiacSign::from_longitude(pos.longitude);
+            DispositorNode {
+                body: pos.body,
+                sign,
+                dispositor: rulership
+                    .rulers(sign)
+                    .into_iter()
+                    .find(|ruler| planets.contains(ruler)),
+            }
+        })
+        .collect();
+
+    let chains: Vec<DispositorChain> = planets
+        .iter()
+        .map(|body| follow_chain(&nodes, *body))
+        .collect();
+
+    let final_dispositor = match chains.first().map(|c| &c.end) {
+        Some(ChainEnd::Final(body)) if chains.iter().all(|c| c.end == ChainEnd::Final(*body)) => {
+            Some(*body)
+        }
+        _ => None,
+    };
+
+    let mut receptions = Vec::new();
+    for (i, a) in nodes.iter().enumerate() {
+        for b in &nodes[i + 1..] {
+            if let Some(kind) = reception(a, b, rulership) {
+                receptions.push(MutualReception {
+                    first: a.body,
+                    second: b.body,
+                    kind,
+                });
+            }
+        }
+    }
+
+    DispositorReport {
+        rulership,
+        nodes,
+        chains,
+        final_dispositor,
+        receptions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::test_position as position;
+
+    #[test]
+    fn test_final_dispositor_and_receptions() {
+        let mut chart = Chart::new();
+        chart.positions = vec![
+            position(CelestialBody::Sun, 10.0),     // Aries -> Mars
+            position(CelestialBody::Moon, 100.0),   // Cancer -> Moon
+            position(CelestialBody::Mercury, 15.0), // Aries -> Mars
+            position(CelestialBody::Venus, 50.0),   // Taurus -> Venus
+            position(CelestialBody::Mars, 20.0),    // Aries -> Mars
+            position(CelestialBody::Jupiter, 5.0),  // Aries -> Mars
+            position(CelestialBody::Saturn, 25.0),  // Aries -> Mars
+        ];
+
+        // Two planets in their own sign: no single final dispositor
+        let report = calculate_dispositors(&chart, Rulership::Traditional);
+        assert_eq!(report.final_dispositor, None);
+        assert_eq!(report.chains[0].end, ChainEnd::Final(CelestialBody::Mars));
+        assert_eq!(report.disposed_by(CelestialBody::Mars).len(), 4);
+
+        // Moon in Taurus and Venus in Cancer: each in the other's sign
+        chart.positions[1].longitude = 40.0;
+        chart.positions[3].longitude = 95.0;
+        let report = calculate_dispositors(&chart, Rulership::Traditional);
+        assert_eq!(
+            report.chains[1].end,
+            ChainEnd::Cycle(vec![CelestialBody::Moon, CelestialBody::Venus])
+        );
+        let kinds: Vec<(CelestialBody, CelestialBody, ReceptionKind)> = report
+            .receptions
+            .iter()
+            .map(|r| (r.first, r.second, r.kind))
+            .collect();
+        assert!(kinds.contains(&(
+            CelestialBody::Moon,
+            CelestialBody::Venus,
+            ReceptionKind::Domicile
+        )));
+        // Sun in Mars's sign, Mars in the Sun's exaltation
+        assert!(kinds.contains(&(
+            CelestialBody::Sun,
+            CelestialBody::Mars,
+            ReceptionKind::Mixed
+        )));
+
+        // Everything back in Aries: Mars disposes of the whole chart
+        chart.positions[1].longitude = 2.0;
+        chart.positions[3].longitude = 3.0;
+        let report = calculate_dispositors(&chart, Rulership::Traditional);
+        assert_eq!(report.final_dispositor, Some(CelestialBody::Mars));
+        let dot = report.to_dot();
+        assert!(dot.contains("\"Sun\" -> \"Mars\";"));
+        assert!(dot.contains("\"Mars\" [shape=doublecircle];"));
+    }
+}
@@ -0,0 +1,438 @@
+use crate::aspects::{AspectEntity, AspectType};
+use crate::chart::{CelestialBody, Chart};
+use crate::returns::longitude_difference;
+use crate::sweph::{
+    calculate_jd, calculate_planet, east_longitude, jd_to_datetime, swe_lun_eclipse_how,
+    swe_lun_eclipse_when, swe_sol_eclipse_how, swe_sol_eclipse_when_glob, swe_sol_eclipse_when_loc,
+    swe_sol_eclipse_where, SE_ECL_ANNULAR, SE_ECL_ANNULAR_TOTAL, SE_ECL_CENTRAL, SE_ECL_PARTIAL,
+    SE_ECL_PENUMBRAL, SE_ECL_TOTAL, SE_ECL_VISIBLE,
+};
+use chrono::{DateTime, Utc};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+/// Swiss Ephemeris eclipse functions want attr[] of at least 20 doubles
+const ATTR_SIZE: usize = 20;
+
+/// Default orb for eclipses on natal points
+pub const ECLIPSE_NATAL_ORB: f64 = 3.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EclipseKind {
+    Solar,
+    Lunar,
+}
+
+impl EclipseKind {
+    pub fn to_string(&self) -> &str {
+        match self {
+            EclipseKind::Solar => "Solar Eclipse",
+            EclipseKind::Lunar => "Lunar Eclipse",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EclipseType {
+    Total,
+    Annular,
+    Hybrid, // Annular-total
+    Partial,
+    Penumbral,
+}
+
+impl EclipseType {
+    pub fn to_string(&self) -> &str {
+        match self {
+            EclipseType::Total => "Total",
+            EclipseType::Annular => "Annular",
+            EclipseType::Hybrid => "Hybrid",
+            EclipseType::Partial => "Partial",
+            EclipseType::Penumbral => "Penumbral",
+        }
+    }
+
+    fn from_flags(flags: i32) -> Option<Self> {
+        if flags & SE_ECL_ANNULAR_TOTAL != 0 {
+            Some(EclipseType::Hybrid)
+        } else if flags & SE_ECL_TOTAL != 0 {
+            Some(EclipseType::Total)
+        } else if flags & SE_ECL_ANNULAR != 0 {
+            Some(EclipseType::Annular)
+        } else if flags & SE_ECL_PARTIAL != 0 {
+            Some(EclipseType::Partial)
+        } else if flags & SE_ECL_PENUMBRAL != 0 {
+            Some(EclipseType::Penumbral)
+        } else {
+            None
+        }
+    }
+}
+
+/// How an eclipse looks from one place
+#[derive(Debug, Clone)]
+pub struct LocalCircumstances {
+    pub visible: bool,
+    pub maximum: Option<DateTime<Utc>>,
+    pub first_contact: Option<DateTime<Utc>>,
+    pub second_contact: Option<DateTime<Utc>>,
+    pub third_contact: Option<DateTime<Utc>>,
+    pub fourth_contact: Option<DateTime<Utc>>,
+    pub magnitude: f64,
+    pub obscuration: f64, // Fraction of the solar disc covered (solar only)
+    pub altitude: f64,    // Apparent altitude of the eclipsed body at maximum
+    pub azimuth: f64,     // From north, clockwise
+}
+
+#[derive(Debug, Clone)]
+pub struct Eclipse {
+    pub kind: EclipseKind,
+    pub eclipse_type: EclipseType,
+    pub central: bool, // Solar: the shadow axis touches the Earth
+    pub maximum: DateTime<Utc>,
+    pub begin: Option<DateTime<Utc>>, // Partial phase (solar: anywhere on Earth)
+    pub end: Option<DateTime<Utc>>,
+    pub totality_begin: Option<DateTime<Utc>>, // Total or annular phase
+    pub totality_end: Option<DateTime<Utc>>,
+    pub penumbral_begin: Option<DateTime<Utc>>, // Lunar only
+    pub penumbral_end: Option<DateTime<Utc>>,
+    pub magnitude: f64, // Solar: NASA magnitude at greatest eclipse; lunar: umbral
+    pub penumbral_magnitude: Option<f64>,
+    pub longitude: f64, // Sidereal degree (Sun for solar, Moon for lunar)
+    pub saros: Option<(i32, i32)>, // (series, member)
+    pub local: Option<LocalCircumstances>,
+}
+
+/// An eclipse conjunct or opposite a natal point
+#[derive(Debug, Clone)]
+pub struct EclipseContact {
+    pub eclipse: Eclipse,
+    pub entity: AspectEntity,
+    pub aspect_type: AspectType,
+    pub orb: f64,
+}
+
+fn optional_time(jd: f64) -> Option<DateTime<Utc>> {
+    if jd > 0.0 {
+        Some(jd_to_datetime(jd))
+    } else {
+        None
+    }
+}
+
+fn saros(attr: &[f64; ATTR_SIZE]) -> Option<(i32, i32)> {
+    if attr[9] > 0.0 {
+        Some((attr[9] as i32, attr[10] as i32))
+    } else {
+        None
+    }
+}
+
+fn eclipse_error(serr: &[c_char; 256]) -> String {
+    let message = unsafe { CStr::from_ptr(serr.as_ptr()) };
+    format!("Eclipse search failed: {}", message.to_string_lossy())
+}
+
+/// Local circumstances of the first solar eclipse visible at `geopos` after `tjd_start`
+fn local_solar(
+    tjd_start: f64,
+    geopos: &mut [f64; 3],
+) -> Result<(i32, [f64; 10], [f64; ATTR_SIZE]), String> {
+    let mut tret = [0.0; 10];
+    let mut attr = [0.0; ATTR_SIZE];
+    let mut serr = [0 as c_char; 256];
+    let flags = unsafe {
+        swe_sol_eclipse_when_loc(
+            tjd_start,
+            0,
+            geopos.as_mut_ptr(),
+            tret.as_mut_ptr(),
+            attr.as_mut_ptr(),
+            0,
+            serr.as_mut_ptr(),
+        )
+    };
+    if flags < 0 {
+        return Err(eclipse_error(&serr));
+    }
+    Ok((flags, tret, attr))
+}
+
+/// Find every solar eclipse between `start` and `end`
+/// With a location (latitude, longitude), local contacts and visibility are included
+pub fn find_solar_eclipses(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    location: Option<(f64, f64)>,
+) -> Result<Vec<Eclipse>, String> {
+    let end_jd = calculate_jd(&end);
+    let mut jd = calculate_jd(&start);
+    let mut eclipses = Vec::new();
+
+    loop {
+        let mut tret = [0.0; 10];
+        let mut serr = [0 as c_char; 256];
+        let flags =
+            unsafe { swe_sol_eclipse_when_glob(jd, 0, 0, tret.as_mut_ptr(), 0, serr.as_mut_ptr()) };
+        if flags < 0 {
+            return Err(eclipse_error(&serr));
+        }
+        let maximum = tret[0];
+        if maximum > end_jd {
+            break;
+        }
+
+        // Magnitude and Saros at the point of greatest eclipse
+        let mut geopos = [0.0; 10];
+        let mut attr = [0.0; ATTR_SIZE];
+        let where_flags = unsafe {
+            swe_sol_eclipse_where(
+                maximum,
+                0,
+                geopos.as_mut_ptr(),
+                attr.as_mut_ptr(),
+                serr.as_mut_ptr(),
+            )
+        };
+        if where_flags < 0 {
+            return Err(eclipse_error(&serr));
+        }
+        let how_flags = unsafe {
+            swe_sol_eclipse_how(
+                maximum,
+                0,
+                geopos.as_mut_ptr(),
+                attr.as_mut_ptr(),
+                serr.as_mut_ptr(),
+            )
+        };
+        if how_flags < 0 {
+            return Err(eclipse_error(&serr));
+        }
+
+        let local = match location {
+            Some((latitude, longitude)) => {
+                let mut place = [east_longitude(longitude), latitude, 0.0];
+                let (local_flags, local_tret, local_attr) = local_solar(maximum - 1.0, &mut place)?;
+                if (local_tret[0] - maximum).abs() < 1.0 {
+                    Some(LocalCircumstances {
+                        visible: local_flags & SE_ECL_VISIBLE != 0,
+                        maximum: optional_time(local_tret[0]),
+                        first_contact: optional_time(local_tret[1]),
+                        second_contact: optional_time(local_tret[2]),
+                        third_contact: optional_time(local_tret[3]),
+                        fourth_contact: optional_time(local_tret[4]),
+                        magnitude: local_attr[8],
+                        obscuration: local_attr[2],
+                        altitude: local_attr[6],
+                        azimuth: (local_attr[4] + 180.0).rem_euclid(360.0),
+                    })
+                } else {
+                    Some(LocalCircumstances {
+                        visible: false,
+                        maximum: None,
+                        first_contact: None,
+                        second_contact: None,
+                        third_contact: None,
+                        fourth_contact: None,
+                        magnitude: 0.0,
+                        obscuration: 0.0,
+                        altitude: 0.0,
+                        azimuth: 0.0,
+                    })
+                }
+            }
+            None => None,
+        };
+
+        eclipses.push(Eclipse {
+            kind: EclipseKind::Solar,
+            eclipse_type: EclipseType::from_flags(flags).unwrap_or(EclipseType::Partial),
+            central: flags & SE_ECL_CENTRAL != 0,
+            maximum: jd_to_datetime(maximum),
+            begin: optional_time(tret[2]),
+            end: optional_time(tret[3]),
+            totality_begin: optional_time(tret[4]),
+            totality_end: optional_time(tret[5]),
+            penumbral_begin: None,
+            penumbral_end: None,
+            magnitude: attr[8],
+            penumbral_magnitude: None,
+            longitude: calculate_planet(maximum, CelestialBody::Sun)?.0,
+            saros: saros(&attr),
+            local,
+        });
+
+        jd = maximum + 1.0;
+    }
+
+    Ok(eclipses)
+}
+
+/// Find every lunar eclipse between `start` and `end`
+/// With a location (latitude, longitude), visibility at maximum is included
+pub fn find_lunar_eclipses(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    location: Option<(f64, f64)>,
+) -> Result<Vec<Eclipse>, String> {
+    let end_jd = calculate_jd(&end);
+    let mut jd = calculate_jd(&start);
+    let mut eclipses = Vec::new();
+
+    loop {
+        let mut tret = [0.0; 10];
+        let mut serr = [0 as c_char; 256];
+        let flags =
+            unsafe { swe_lun_eclipse_when(jd, 0, 0, tret.as_mut_ptr(), 0, serr.as_mut_ptr()) };
+        if flags < 0 {
+            return Err(eclipse_error(&serr));
+        }
+        let maximum = tret[0];
+        if maximum > end_jd {
+            break;
+        }
+
+        let mut attr = [0.0; ATTR_SIZE];
+        let local = match location {
+            Some((latitude, longitude)) => {
+                let mut place = [east_longitude(longitude), latitude, 0.0];
+                let how_flags = unsafe {
+                    swe_lun_eclipse_how(
+                        maximum,
+                        0,
+                        place.as_mut_ptr(),
+                        attr.as_mut_ptr(),
+                        serr.as_mut_ptr(),
+                    )
+                };
+                if how_flags < 0 {
+                    return Err(eclipse_error(&serr));
+                }
+                Some(LocalCircumstances {
+                    visible: attr[6] > 0.0,
+                    maximum: Some(jd_to_datetime(maximum)),
+                    first_contact: optional_time(tret[2]),
+                    second_contact: optional_time(tret[4]),
+                    third_contact: optional_time(tret[5]),
+                    fourth_contact: optional_time(tret[3]),
+                    magnitude: attr[0],
+                    obscuration: 0.0,
+                    altitude: attr[6],
+                    azimuth: (attr[4] + 180.0).rem_euclid(360.0),
+                })
+            }
+            None => {
+                let how_flags = unsafe {
+                    swe_lun_eclipse_how(
+                        maximum,
+                        0,
+                        std::ptr::null_mut(),
+                        attr.as_mut_ptr(),
+                        serr.as_mut_ptr(),
+                    )
+                };
+                if how_flags < 0 {
+                    return Err(eclipse_error(&serr));
+                }
+                None
+            }
+        };
+
+        eclipses.push(Eclipse {
+            kind: EclipseKind::Lunar,
+            eclipse_type: EclipseType::from_flags(flags).unwrap_or(EclipseType::Penumbral),
+            central: false,
+            maximum: jd_to_datetime(maximum),
+            begin: optional_time(tret[2]),
+            end: optional_time(tret[3]),
+            totality_begin: optional_time(tret[4]),
+            totality_end: optional_time(tret[5]),
+            penumbral_begin: optional_time(tret[6]),
+            penumbral_end: optional_time(tret[7]),
+            magnitude: attr[0],
+            penumbral_magnitude: Some(attr[1]),
+            longitude: calculate_planet(maximum, CelestialBody::Moon)?.0,
+            saros: saros(&attr),
+            local,
+        });
+
+        jd = maximum + 1.0;
+    }
+
+    Ok(eclipses)
+}
+
+/// Solar and lunar eclipses between `start` and `end`, in time order
+pub fn find_eclipses(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    location: Option<(f64, f64)>,
+) -> Result<Vec<Eclipse>, String> {
+    let mut eclipses = find_solar_eclipses(start, end, location)?;
+    eclipses.extend(find_lunar_eclipses(start, end, location)?);
+    eclipses.sort_by_key(|e| e.maximum);
+    Ok(eclipses)
+}
+
+/// Eclipses falling conjunct or opposite natal planets and angles within `orb`
+pub fn calculate_eclipse_contacts(
+    eclipses: &[Eclipse],
+    chart: &Chart,
+    orb: f64,
+) -> Vec<EclipseContact> {
+    let points = chart.points();
+
+    let mut contacts = Vec::new();
+    for eclipse in eclipses {
+        for (entity, longitude) in &points {
+            for aspect_type in [AspectType::Conjunction, AspectType::Opposition] {
+                let target = (longitude + aspect_type.angle()).rem_euclid(360.0);
+                let distance = longitude_difference(target, eclipse.longitude).abs();
+                if distance <= orb {
+                    contacts.push(EclipseContact {
+                        eclipse: eclipse.clone(),
+                        entity: entity.clone(),
+                        aspect_type,
+                        orb: distance,
+                    });
+                }
+            }
+        }
+    }
+    contacts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sweph::init_sweph;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_2024_eclipses() {
+        init_sweph();
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        // Dallas, on the path of totality of 2024-04-08
+        let solar = find_solar_eclipses(start, end, Some((32.78, -96.80))).unwrap();
+        assert_eq!(solar.len(), 2);
+        let april = &solar[0];
+        assert_eq!(april.eclipse_type, EclipseType::Total);
+        assert_eq!(april.saros.map(|s| s.0), Some(139));
+        assert_eq!(april.maximum.format("%Y-%m-%d").to_string(), "2024-04-08");
+        let local = april.local.as_ref().unwrap();
+        assert!(local.visible && local.second_contact.is_some());
+
+        // October annular eclipse is not visible from Dallas
+        assert_eq!(solar[1].eclipse_type, EclipseType::Annular);
+        assert!(!solar[1].local.as_ref().unwrap().visible);
+
+        // 2024-03-25 penumbral and 2024-09-18 partial lunar eclipses
+        let lunar = find_lunar_eclipses(start, end, None).unwrap();
+        assert_eq!(lunar.len(), 2);
+        assert_eq!(lunar[0].eclipse_type, EclipseType::Penumbral);
+        assert_eq!(lunar[1].eclipse_type, EclipseType::Partial);
+    }
+}
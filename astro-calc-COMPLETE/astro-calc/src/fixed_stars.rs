@@ -0,0 +1,457 @@
+use crate::aspects::{angular_separation, AspectEntity};
+use crate::chart::{CelestialBody, Chart};
+use crate::sweph::{swe_calc_ut, swe_fixstar2_mag, swe_fixstar2_ut, ZodiacMode, SEFLG_EQUATORIAL};
+use serde::{Deserialize, Serialize};
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_double};
+
+/// Star name buffer size (Swiss Ephemeris writes the full "name,nomenclature" back)
+const STAR_NAME_BUFFER: usize = 512;
+
+/// The four Royal stars (Watchers of the Heavens)
+pub const ROYAL_STARS: [&str; 4] = ["Aldebaran", "Regulus", "Antares", "Fomalhaut"];
+
+/// The fifteen Behenian stars of medieval astrology
+pub const BEHENIAN_STARS: [&str; 15] = [
+    "Algol",
+    "Alcyone", // Pleiades
+    "Aldebaran",
+    "Capella",
+    "Sirius",
+    "Procyon",
+    "Regulus",
+    "Alkaid",
+    "Algorab",
+    "Spica",
+    "Arcturus",
+    "Alphecca",
+    "Antares",
+    "Vega",
+    "Deneb Algedi",
+];
+
+/// Which stars to calculate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StarList {
+    Royal,
+    Behenian,
+    /// Any names (or ",alTau" style nomenclature) found in sefstars.txt
+    Custom(Vec<String>),
+}
+
+impl StarList {
+    pub fn names(&self) -> Vec<String> {
+        match self {
+            StarList::Royal => ROYAL_STARS.iter().map(|s| s.to_string()).collect(),
+            StarList::Behenian => BEHENIAN_STARS.iter().map(|s| s.to_string()).collect(),
+            StarList::Custom(names) => names.clone(),
+        }
+    }
+}
+
+/// Fixed star calculation settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixedStarOptions {
+    pub stars: StarList,
+    pub orb: f64,       // Conjunction orb in degrees of longitude
+    pub paran_orb: f64, // Paran orb in degrees of RAMC (1° ≈ 4 minutes)
+    #[serde(skip)]
+    pub mode: ZodiacMode, // Frame for reported star longitudes
+}
+
+impl Default for FixedStarOptions {
+    fn default() -> Self {
+        Self {
+            stars: StarList::Behenian,
+            orb: 1.0,
+            paran_orb: 1.0,
+            mode: ZodiacMode::Sidereal,
+        }
+    }
+}
+
+impl FixedStarOptions {
+    pub fn new(stars: StarList) -> Self {
+        Self {
+            stars,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_orb(mut self, orb: f64) -> Self {
+        self.orb = orb;
+        self
+    }
+
+    pub fn with_paran_orb(mut self, paran_orb: f64) -> Self {
+        self.paran_orb = paran_orb;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: ZodiacMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// A calculated fixed star
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixedStar {
+    pub name: String,         // Traditional name, e.g. "Aldebaran"
+    pub nomenclature: String, // Bayer designation, e.g. "alTau"
+    pub longitude: f64,       // Ecliptic longitude in the requested frame
+    pub latitude: f64,        // Ecliptic latitude
+    pub right_ascension: f64,
+    pub declination: f64,
+    pub magnitude: f64,
+}
+
+/// A star conjunct a natal planet or angle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarConjunction {
+    pub star: String,
+    pub entity: AspectEntity,
+    pub orb: f64,
+}
+
+/// Horizon/meridian event used for parans
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParanEvent {
+    Rising,
+    Culminating,
+    Setting,
+    AntiCulminating,
+}
+
+impl ParanEvent {
+    pub fn to_string(&self) -> &str {
+        match self {
+            ParanEvent::Rising => "rising",
+            ParanEvent::Culminating => "culminating",
+            ParanEvent::Setting => "setting",
+            ParanEvent::AntiCulminating => "anti-culminating",
+        }
+    }
+}
+
+/// A star and a planet on angles at the same moment (same RAMC) at the birth latitude
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Paran {
+    pub star: String,
+    pub star_event: ParanEvent,
+    pub body: CelestialBody,
+    pub body_event: ParanEvent,
+    pub orb: f64, // Degrees of RAMC
+}
+
+/// Fixed star results for a chart
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FixedStarReport {
+    pub stars: Vec<FixedStar>,
+    pub conjunctions: Vec<StarConjunction>,
+    pub parans: Vec<Paran>,
+}
+
+/// Copy a star name into a writable C buffer
+fn star_buffer(name: &str) -> [c_char; STAR_NAME_BUFFER] {
+    let mut star = [0 as c_char; STAR_NAME_BUFFER];
+    for (i, byte) in name.bytes().take(STAR_NAME_BUFFER - 1).enumerate() {
+        star[i] = byte as c_char;
+    }
+    star
+}
+
+/// Call swe_fixstar2_ut and return (full name, xx)
+fn fixstar(name: &str, jd: c_double, flags: i32) -> Result<(String, [f64; 6]), String> {
+    let mut star = star_buffer(name);
+    let mut xx = [0.0; 6];
+    let mut serr = [0 as c_char; 256];
+
+    let result = unsafe {
+        swe_fixstar2_ut(
+            star.as_mut_ptr(),
+            jd,
+            flags,
+            xx.as_mut_ptr(),
+            serr.as_mut_ptr(),
+        )
+    };
+    if result < 0 {
+        let message = unsafe { CStr::from_ptr(serr.as_ptr()) };
+        return Err(format!(
+            "Failed to calculate star {}: {}",
+            name,
+            message.to_string_lossy()
+        ));
+    }
+
+    let full_name = unsafe { CStr::from_ptr(star.as_ptr()) }
+        .to_string_lossy()
+        .into_owned();
+    Ok((full_name, xx))
+}
+
+/// Calculate a single fixed star by name
+pub fn calculate_fixed_star(jd: f64, name: &str, mode: ZodiacMode) -> Result<FixedStar, String> {
+    let (full_name, ecliptic) = fixstar(name, jd, mode.flags())?;
+    let (_, equatorial) = fixstar(name, jd, SEFLG_EQUATORIAL)?;
+
+    let mut star = star_buffer(name);
+    let mut magnitude = 0.0;
+    let mut serr = [0 as c_char; 256];
+    let result = unsafe { swe_fixstar2_mag(star.as_mut_ptr(), &mut magnitude, serr.as_mut_ptr()) };
+    if result < 0 {
+        let message = unsafe { CStr::from_ptr(serr.as_ptr()) };
+        return Err(format!(
+            "Failed to get magnitude of star {}: {}",
+            name,
+            message.to_string_lossy()
+        ));
+    }
+
+    let mut parts = full_name.splitn(2, ',');
+    let star_name = parts.next().unwrap_or(name).trim().to_string();
+    let nomenclature = parts.next().unwrap_or("").trim().to_string();
+
+    Ok(FixedStar {
+        name: star_name,
+        nomenclature,
+        longitude: mode.normalize(ecliptic[0]),
+        latitude: ecliptic[1],
+        right_ascension: equatorial[0],
+        declination: equatorial[1],
+        magnitude,
+    })
+}
+
+/// RAMC at which a body with the given RA/declination reaches an event,
+/// or None if it never rises/sets at this latitude (circumpolar)
+fn paran_ramc(ra: f64, dec: f64, latitude: f64, event: ParanEvent) -> Option<f64> {
+    let ramc = match event {
+        ParanEvent::Culminating => ra,
+        ParanEvent::AntiCulminating => ra + 180.0,
+        ParanEvent::Rising | ParanEvent::Setting => {
+            let cos_h = -latitude.to_radians().tan() * dec.to_radians().tan();
+            if cos_h.abs() > 1.0 {
+                return None;
+            }
+            let semi_arc = cos_h.acos().to_degrees();
+            if event == ParanEvent::Rising {
+                ra - semi_arc
+            } else {
+                ra + semi_arc
+            }
+        }
+    };
+    Some(ramc.rem_euclid(360.0))
+}
+
+/// Calculate star positions, conjunctions to chart points and parans at the birth latitude
+pub fn calculate_fixed_star_report(
+    jd: f64,
+    latitude: f64,
+    chart: &Chart,
+    options: &FixedStarOptions,
+) -> Result<FixedStarReport, String> {
+    let mut report = FixedStarReport::default();
+    let events = [
+        ParanEvent::Rising,
+        ParanEvent::Culminating,
+        ParanEvent::Setting,
+        ParanEvent::AntiCulminating,
+    ];
+
+    for name in options.stars.names() {
+        let star = calculate_fixed_star(jd, &name, options.mode)?;
+
+        // Conjunctions are measured in the chart's own (sidereal) frame
+        let sidereal_lon = match options.mode {
+            ZodiacMode::Sidereal => star.longitude,
+            ZodiacMode::Tropical => {
+                calculate_fixed_star(jd, &name, ZodiacMode::Sidereal)?.longitude
+            }
+        };
+
+        for (entity, lon) in chart.points() {
+            let orb = angular_separation(sidereal_lon, lon);
+            if orb <= options.orb {
+                report.conjunctions.push(StarConjunction {
+                    star: star.name.clone(),
+                    entity,
+                    orb,
+                });
+            }
+        }
+
+        // Parans: physical bodies only (nodes, Lilith and lots are not seen to rise)
+        for pos in chart.positions.iter().filter(|p| is_physical_body(p.body)) {
+            let (ra, dec) = body_equatorial(jd, pos.body)?;
+
+            for star_event in events {
+                let Some(star_ramc) =
+                    paran_ramc(star.right_ascension, star.declination, latitude, star_event)
+                else {
+                    continue;
+                };
+                for body_event in events {
+                    let Some(body_ramc) = paran_ramc(ra, dec, latitude, body_event) else {
+                        continue;
+                    };
+                    let orb = angular_separation(star_ramc, body_ramc);
+                    if orb <= options.paran_orb {
+                        report.parans.push(Paran {
+                            star: star.name.clone(),
+                            star_event,
+                            body: pos.body,
+                            body_event,
+                            orb,
+                        });
+                    }
+                }
+            }
+        }
+
+        report.stars.push(star);
+    }
+
+    report
+        .conjunctions
+        .sort_by(|a, b| a.orb.partial_cmp(&b.orb).unwrap());
+    report
+        .parans
+        .sort_by(|a, b| a.orb.partial_cmp(&b.orb).unwrap());
+
+    Ok(report)
+}
+
+/// Right ascension and declination of a body (None if it can't be calculated)
+fn body_equatorial(jd: f64, body: CelestialBody) -> Result<(f64, f64), String> {
+    let planet_id =
+        crate::sweph::get_planet_id(body).ok_or(format!("{} has no ephemeris position", body))?;
+    let mut xx = [0.0; 6];
+    let mut serr = [0 as c_char; 256];
+    let result = unsafe {
+        swe_calc_ut(
+            jd,
+            planet_id,
+            SEFLG_EQUATORIAL,
+            xx.as_mut_ptr(),
+            serr.as_mut_ptr(),
+        )
+    };
+    if result < 0 {
+        let message = unsafe { CStr::from_ptr(serr.as_ptr()) };
+        return Err(format!(
+            "Failed to calculate {}: {}",
+            body,
+            message.to_string_lossy()
+        ));
+    }
+    Ok((xx[0], xx[1]))
+}
+
+/// Planets and asteroids: bodies that actually rise, culminate and set
+fn is_physical_body(body: CelestialBody) -> bool {
+    matches!(
+        body,
+        CelestialBody::Sun
+            | CelestialBody::Moon
+            | CelestialBody::Mercury
+            | CelestialBody::Venus
+            | CelestialBody::Mars
+            | CelestialBody::Jupiter
+            | CelestialBody::Saturn
+            | CelestialBody::Uranus
+            | CelestialBody::Neptune
+            | CelestialBody::Pluto
+            | CelestialBody::Chiron
+            | CelestialBody::Pholus
+            | CelestialBody::Ceres
+            | CelestialBody::Pallas
+            | CelestialBody::Juno
+            | CelestialBody::Vesta
+            | CelestialBody::Eris
+            | CelestialBody::Asteroid(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::test_position;
+
+    #[test]
+    fn test_paran_ramc() {
+        // On the equator every body is up for exactly 12 hours
+        let rise = paran_ramc(100.0, 20.0, 0.0, ParanEvent::Rising).unwrap();
+        let set = paran_ramc(100.0, 20.0, 0.0, ParanEvent::Setting).unwrap();
+        assert!((rise - 10.0).abs() < 1e-9);
+        assert!((set - 190.0).abs() < 1e-9);
+
+        // Circumpolar at high latitude
+        assert!(paran_ramc(0.0, 80.0, 60.0, ParanEvent::Rising).is_none());
+        assert_eq!(
+            paran_ramc(350.0, 80.0, 60.0, ParanEvent::AntiCulminating),
+            Some(170.0)
+        );
+    }
+
+    #[test]
+    fn test_regulus_report() {
+        crate::sweph::init_sweph();
+        let jd = 2451545.0; // J2000
+
+        // Regulus sat at about 29°50' Leo in 2000
+        let regulus = calculate_fixed_star(jd, "Regulus", ZodiacMode::Tropical).unwrap();
+        assert_eq!(regulus.name, "Regulus");
+        assert!((regulus.longitude - 149.83).abs() < 0.1);
+        assert!(regulus.magnitude > 1.0 && regulus.magnitude < 1.6);
+        assert!(calculate_fixed_star(jd, "No Such Star", ZodiacMode::Tropical).is_err());
+
+        // A Sun placed on the star's sidereal longitude is reported as conjunct
+        let sidereal = calculate_fixed_star(jd, "Regulus", ZodiacMode::Sidereal).unwrap();
+        let mut chart = Chart::new();
+        chart
+            .positions
+            .push(test_position(CelestialBody::Sun, sidereal.longitude + 0.4));
+        let options = FixedStarOptions::new(StarList::Custom(vec!["Regulus".to_string()]))
+            .with_mode(ZodiacMode::Tropical);
+        let report = calculate_fixed_star_report(jd, 51.5, &chart, &options).unwrap();
+        assert!((report.stars[0].longitude - regulus.longitude).abs() < 1e-9);
+        assert_eq!(report.conjunctions.len(), 1);
+        assert_eq!(
+            report.conjunctions[0].entity,
+            AspectEntity::Body(CelestialBody::Sun)
+        );
+        assert!((report.conjunctions[0].orb - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_parans_skip_points_and_report_missing_files() {
+        crate::sweph::init_sweph();
+        let jd = 2451545.0;
+        let options = FixedStarOptions::new(StarList::Custom(vec!["Regulus".to_string()]))
+            .with_paran_orb(360.0);
+
+        // The node has an ephemeris position but never rises, so it makes no parans
+        let mut chart = Chart::new();
+        chart
+            .positions
+            .push(test_position(CelestialBody::TrueNode, 10.0));
+        let report = calculate_fixed_star_report(jd, 51.5, &chart, &options).unwrap();
+        assert!(report.parans.is_empty());
+
+        chart
+            .positions
+            .push(test_position(CelestialBody::Sun, 280.0));
+        let report = calculate_fixed_star_report(jd, 51.5, &chart, &options).unwrap();
+        assert!(report.parans.iter().all(|p| p.body == CelestialBody::Sun));
+        assert!(!report.parans.is_empty());
+
+        // No asteroid file for Eros: an error rather than a silently missing body
+        chart
+            .positions
+            .push(test_position(CelestialBody::Asteroid(433), 50.0));
+        assert!(calculate_fixed_star_report(jd, 51.5, &chart, &options).is_err());
+    }
+}
@@ -1,5 +1,23 @@
-use crate::aspects::{AspectEntity, calculate_aspects};
+use crate::antiscia::AntisciaReport;
+use crate::aspects::{Aspect, AspectEntity, calculate_aspects};
 use crate::chart::{AnglePoint, Chart, CelestialBody, Position, ZodiacSign};
+use crate::dignities::{exaltation_ruler, face_ruler, term_ruler, DignityReport};
+use crate::dispositors::{ChainEnd, DispositorReport};
+use crate::directions::{DirectionMethod, DirectionPerfection};
+use crate::eclipses::{Eclipse, EclipseContact};
+use crate::fixed_stars::FixedStarReport;
+use crate::harmonics::HarmonicScore;
+use crate::heliacal::HeliacalEvent;
+use crate::house_rulers::{HouseRulerReport, RulerPlacement};
+use crate::returns::{PlanetaryReturn, ReturnChart};
+use crate::midpoints::{MidpointContact, MidpointReport};
+use crate::moon::MoonCalendar;
+use crate::planetary_hours::PlanetaryDay;
+use crate::rise_set::DailyAlmanac;
+use crate::stations::StationCalendar;
+use crate::statistics::{calculate_chart_statistics, ChartStatistics, Tally};
+use crate::sweph::ChartInput;
+use crate::transit_search::{transit_calendar_lines, TransitEvent};
 
 /// Format a position in the user's requested format
 fn format_position(pos: &Position, label_suffix: &str) -> String {
@@ -30,7 +48,7 @@ fn format_aspects(aspects: &[crate::aspects::Aspect]) -> String {
 
     let aspect_strs: Vec<String> = aspects
         .iter()
-        .map(|a| format!("{} {}", a.aspect_type.symbol(), a.entity2.to_string()))
+        .map(|a| format!("{} {}", a.aspect_type.symbol(), a.entity2))
         .collect();
 
     aspect_strs.join(", ")
@@ -38,19 +56,83 @@ fn format_aspects(aspects: &[crate::aspects::Aspect]) -> String {
 
 /// Format a natal chart
 pub fn format_natal_chart(chart: &Chart) -> String {
-    format_single_chart(chart, "H", None)
+    let mut output = format_single_chart(chart, "H", None);
+
+    if let Some(report) = &chart.house_rulers {
+        output.push_str("\n\n");
+        output.push_str(&format_house_rulers(report));
+    }
+
+    if !chart.positions.is_empty() {
+        output.push_str("\n\n");
+        output.push_str(&format_chart_statistics(&calculate_chart_statistics(chart)));
+    }
+
+    if let Some(report) = &chart.fixed_stars {
+        output.push_str("\n\n");
+        output.push_str(&format_fixed_stars(report));
+    }
+
+    output
 }
 
-/// Format a transit chart with natal reference
-pub fn format_transit_chart(natal: &Chart, transit: &Chart) -> String {
+/// Format the fixed star section (positions, conjunctions, parans)
+pub fn format_fixed_stars(report: &FixedStarReport) -> String {
     let mut output = Vec::new();
-    
-    output.push("=== NATAL CHART ===".to_string());
-    output.push(String::new());
-    output.push(format_single_chart(natal, "H", None));
-    output.push(String::new());
-    output.push("=== TRANSITING POSITIONS ===".to_string());
+
+    output.push("Fixed Stars:".to_string());
     output.push(String::new());
+
+    for star in &report.stars {
+        let degree = (star.longitude % 30.0).floor() as u32;
+        output.push(format!(
+            "{} {} {} (mag {:.1}).",
+            star.name,
+            degree,
+            ZodiacSign::from_longitude(star.longitude).to_string(),
+            star.magnitude
+        ));
+    }
+
+    if !report.conjunctions.is_empty() {
+        output.push(String::new());
+        for conjunction in &report.conjunctions {
+            output.push(format!(
+                "{} conjunct {} ({:.1}°).",
+                conjunction.star,
+                conjunction.entity,
+                conjunction.orb
+            ));
+        }
+    }
+
+    if !report.parans.is_empty() {
+        output.push(String::new());
+        for paran in &report.parans {
+            output.push(format!(
+                "{} {} while {} {} ({:.1}°).",
+                paran.star,
+                paran.star_event.to_string(),
+                paran.body,
+                paran.body_event.to_string(),
+                paran.orb
+            ));
+        }
+    }
+
+    output.join("\n")
+}
+
+/// Format a transit chart with natal reference
+pub fn format_transit_chart(natal: &Chart, transit: &Chart) -> String {
+    let mut output = vec![
+        "=== NATAL CHART ===".to_string(),
+        String::new(),
+        format_single_chart(natal, "H", None),
+        String::new(),
+        "=== TRANSITING POSITIONS ===".to_string(),
+        String::new(),
+    ];
     
     // Calculate aspects between transit planets and natal planets
     let mut combined_chart = transit.clone();
@@ -80,12 +162,12 @@ pub fn format_transit_chart(natal: &Chart, transit: &Chart) -> String {
         
         let line = if aspect_str.is_empty() {
             format!("Transiting {} {} (in natal house {}).", 
-                pos.body.to_string(), 
+                pos.body, 
                 format_position_short(pos),
                 pos.house)
         } else {
             format!("Transiting {} {} (in natal house {}); {}.", 
-                pos.body.to_string(), 
+                pos.body, 
                 format_position_short(pos),
                 pos.house,
                 aspect_str)
@@ -96,6 +178,708 @@ pub fn format_transit_chart(natal: &Chart, transit: &Chart) -> String {
     output.join("\n")
 }
 
+/// Format a progressed chart with natal reference and progressed-to-natal aspects
+pub fn format_progressed_chart(natal: &Chart, progressed: &Chart, aspects: &[Aspect]) -> String {
+    let mut output = vec![
+        "=== NATAL CHART ===".to_string(),
+        String::new(),
+        format_single_chart(natal, "H", None),
+        String::new(),
+        "=== PROGRESSED POSITIONS ===".to_string(),
+        String::new(),
+    ];
+
+    // Aspects from a progressed entity to natal entities
+    let aspects_to_natal = |entity: &AspectEntity| -> String {
+        aspects
+            .iter()
+            .filter(|a| &a.entity1 == entity)
+            .map(|a| format!("{} natal {} ({:.1}°)", a.aspect_type.symbol(), a.entity2, a.orb))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    for pos in &progressed.positions {
+        let aspect_str = aspects_to_natal(&AspectEntity::Body(pos.body));
+        let line = format!(
+            "Progressed {} {} (progressed house {}, natal house {})",
+            pos.body,
+            format_position_short(pos),
+            pos.house,
+            natal.houses.get_house(pos.longitude)
+        );
+        if aspect_str.is_empty() {
+            output.push(format!("{}.", line));
+        } else {
+            output.push(format!("{}; {}.", line, aspect_str));
+        }
+    }
+
+    for angle_pos in &progressed.angles {
+        let aspect_str = aspects_to_natal(&AspectEntity::Angle(angle_pos.angle));
+        let line = format!(
+            "Progressed {} {} {}",
+            angle_pos.angle.to_string(),
+            angle_pos.degree_in_sign().floor() as u32,
+            angle_pos.sign().to_string()
+        );
+        if aspect_str.is_empty() {
+            output.push(format!("{}.", line));
+        } else {
+            output.push(format!("{}; {}.", line, aspect_str));
+        }
+    }
+
+    output.join("\n")
+}
+
+/// Format a directed chart against the natal chart (directed positions carry natal houses)
+pub fn format_directed_chart(natal: &Chart, directed: &Chart, aspects: &[Aspect], method: DirectionMethod) -> String {
+    let mut output = Vec::new();
+
+    output.push("=== NATAL CHART ===".to_string());
+    output.push(String::new());
+    output.push(format_single_chart(natal, "H", None));
+    output.push(String::new());
+    output.push(format!("=== {} DIRECTIONS ===", method.to_string().to_uppercase()));
+    output.push(String::new());
+
+    let aspects_to_natal = |entity: &AspectEntity| -> String {
+        aspects
+            .iter()
+            .filter(|a| &a.entity1 == entity)
+            .map(|a| format!("{} natal {} ({:.2}°)", a.aspect_type.symbol(), a.entity2, a.orb))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    for pos in &directed.positions {
+        let aspect_str = aspects_to_natal(&AspectEntity::Body(pos.body));
+        let line = format!("Directed {} {} (natal house {})", pos.body, format_position_short(pos), pos.house);
+        if aspect_str.is_empty() {
+            output.push(format!("{}.", line));
+        } else {
+            output.push(format!("{}; {}.", line, aspect_str));
+        }
+    }
+
+    for angle_pos in &directed.angles {
+        let aspect_str = aspects_to_natal(&AspectEntity::Angle(angle_pos.angle));
+        let line = format!(
+            "Directed {} {} {}",
+            angle_pos.angle.to_string(),
+            angle_pos.degree_in_sign().floor() as u32,
+            angle_pos.sign().to_string()
+        );
+        if aspect_str.is_empty() {
+            output.push(format!("{}.", line));
+        } else {
+            output.push(format!("{}; {}.", line, aspect_str));
+        }
+    }
+
+    output.join("\n")
+}
+
+/// Format a chronological list of direction perfections
+pub fn format_direction_perfections(perfections: &[DirectionPerfection]) -> String {
+    perfections
+        .iter()
+        .map(|p| {
+            format!(
+                "{}: Directed {} {} natal {} (arc {:.2}°)",
+                p.datetime.format("%Y-%m-%d"),
+                p.directed,
+                p.aspect_type.symbol(),
+                p.natal,
+                p.arc
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format a Solar/Lunar Return chart with natal house overlays
+pub fn format_return_chart(ret: &ReturnChart) -> String {
+    let mut output = Vec::new();
+
+    output.push("=== NATAL CHART ===".to_string());
+    output.push(String::new());
+    output.push(format_single_chart(&ret.natal, "H", None));
+    output.push(String::new());
+    output.push(format!(
+        "=== {} ({} UTC, {:.4}, {:.4}) ===",
+        ret.kind.to_string().to_uppercase(),
+        ret.datetime.format("%Y-%m-%d %H:%M:%S"),
+        ret.latitude,
+        ret.longitude
+    ));
+    output.push(String::new());
+    output.push(format_single_chart(&ret.chart, "H", None));
+    output.push(String::new());
+    output.push("=== RETURN POSITIONS IN NATAL HOUSES ===".to_string());
+    output.push(String::new());
+
+    for pos in &ret.chart.positions {
+        output.push(format!(
+            "Return {} {} (return house {}, natal house {}).",
+            pos.body,
+            format_position_short(pos),
+            pos.house,
+            ret.natal.houses.get_house(pos.longitude)
+        ));
+    }
+
+    for angle_pos in &ret.chart.angles {
+        output.push(format!(
+            "Return {} {} {} (natal house {}).",
+            angle_pos.angle.to_string(),
+            angle_pos.degree_in_sign().floor() as u32,
+            angle_pos.sign().to_string(),
+            ret.natal.houses.get_house(angle_pos.longitude)
+        ));
+    }
+
+    output.join("\n")
+}
+
+/// Format a chronological list of planetary returns (one line per pass)
+pub fn format_planetary_returns(returns: &[PlanetaryReturn]) -> String {
+    returns
+        .iter()
+        .map(|r| {
+            let pass = if r.passes > 1 {
+                format!(", pass {} of {}{}", r.pass, r.passes, if r.retrograde { " (retrograde)" } else { "" })
+            } else {
+                String::new()
+            };
+            format!(
+                "{} UTC: {} at {} {} (age {:.2}{}).",
+                r.datetime.format("%Y-%m-%d %H:%M"),
+                r.label(),
+                (r.target_longitude % 30.0).floor() as u32,
+                ZodiacSign::from_longitude(r.target_longitude).to_string(),
+                r.age,
+                pass
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format a chronological transit calendar (enter orb, exact hits, leave orb)
+pub fn format_transit_calendar(events: &[TransitEvent]) -> String {
+    transit_calendar_lines(events)
+        .iter()
+        .map(|(datetime, text)| format!("{} UTC: {}.", datetime.format("%Y-%m-%d %H:%M"), text))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format stations, shadows and ingresses as a dated list
+pub fn format_station_calendar(calendar: &StationCalendar) -> String {
+    calendar
+        .entries()
+        .iter()
+        .map(|(datetime, text)| format!("{} UTC: {}.", datetime.format("%Y-%m-%d %H:%M"), text))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format lunations, void-of-course periods and Moon ingresses as a dated list
+pub fn format_moon_calendar(calendar: &MoonCalendar) -> String {
+    calendar
+        .events()
+        .iter()
+        .map(|event| match event.end {
+            Some(end) => format!(
+                "{} - {} UTC: {}.",
+                event.start.format("%Y-%m-%d %H:%M"),
+                end.format("%Y-%m-%d %H:%M"),
+                event.summary
+            ),
+            None => format!("{} UTC: {}.", event.start.format("%Y-%m-%d %H:%M"), event.summary),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format a list of eclipses with degree, Saros series and local visibility
+pub fn format_eclipses(eclipses: &[Eclipse]) -> String {
+    eclipses
+        .iter()
+        .map(|e| {
+            let mut line = format!(
+                "{} UTC: {} {} at {} {} (magnitude {:.3}",
+                e.maximum.format("%Y-%m-%d %H:%M"),
+                e.eclipse_type.to_string(),
+                e.kind.to_string(),
+                (e.longitude % 30.0).floor() as u32,
+                ZodiacSign::from_longitude(e.longitude).to_string(),
+                e.magnitude
+            );
+            if let Some((series, member)) = e.saros {
+                line.push_str(&format!(", Saros {} #{}", series, member));
+            }
+            line.push(')');
+            if let Some(local) = &e.local {
+                if local.visible {
+                    line.push_str(&format!(
+                        "; visible locally, magnitude {:.3}, altitude {:.0}°",
+                        local.magnitude, local.altitude
+                    ));
+                } else {
+                    line.push_str("; not visible locally");
+                }
+            }
+            format!("{}.", line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format eclipses falling on natal points
+pub fn format_eclipse_contacts(contacts: &[EclipseContact]) -> String {
+    contacts
+        .iter()
+        .map(|c| {
+            format!(
+                "{}: {} {} {} natal {} ({:.1}°).",
+                c.eclipse.maximum.format("%Y-%m-%d"),
+                c.eclipse.eclipse_type.to_string(),
+                c.eclipse.kind.to_string(),
+                c.aspect_type.symbol(),
+                c.entity,
+                c.orb
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format a daily almanac (rise, culmination and set times plus twilight)
+pub fn format_daily_almanac(almanac: &DailyAlmanac) -> String {
+    let time = |dt: Option<chrono::DateTime<chrono::Utc>>| {
+        dt.map(|d| d.format("%H:%M").to_string())
+            .unwrap_or_else(|| "--:--".to_string())
+    };
+
+    let mut output = vec![format!(
+        "=== ALMANAC {} ({:.2}, {:.2}) ===",
+        almanac.date.format("%Y-%m-%d"),
+        almanac.latitude,
+        almanac.longitude
+    )];
+    output.push(String::new());
+    output.push("Times in UTC: rise / culmination / set".to_string());
+
+    for times in &almanac.bodies {
+        let mut line = format!(
+            "{}: {} / {} / {}",
+            times.body,
+            time(times.rise),
+            time(times.upper_culmination),
+            time(times.set)
+        );
+        if times.circumpolar {
+            line.push_str(" (does not rise or set)");
+        }
+        output.push(line);
+    }
+
+    if let Some(length) = almanac.day_length() {
+        output.push(format!(
+            "Day length: {}h {:02}m",
+            length.num_hours(),
+            length.num_minutes() % 60
+        ));
+    }
+
+    output.push(String::new());
+    for twilight in &almanac.twilight {
+        output.push(format!(
+            "{} twilight: dawn {}, dusk {}",
+            twilight.twilight.to_string(),
+            time(twilight.dawn),
+            time(twilight.dusk)
+        ));
+    }
+
+    output.join("\n")
+}
+
+/// Format planetary hour tables, one block per day
+pub fn format_planetary_hours(days: &[PlanetaryDay]) -> String {
+    days.iter()
+        .map(|day| {
+            let mut lines = vec![format!(
+                "=== {} ({} day) ===",
+                day.date.format("%A %Y-%m-%d"),
+                day.day_ruler.to_string()
+            )];
+            lines.push(format!(
+                "Sunrise {} UTC, sunset {} UTC",
+                day.sunrise.format("%H:%M"),
+                day.sunset.format("%H:%M")
+            ));
+            for hour in &day.hours {
+                lines.push(format!(
+                    "{:>2}. {} - {} {} ({})",
+                    hour.number,
+                    hour.start.format("%H:%M"),
+                    hour.end.format("%H:%M"),
+                    hour.ruler,
+                    if hour.is_day { "day" } else { "night" }
+                ));
+            }
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Format heliacal risings, settings and acronychal events
+pub fn format_heliacal_events(events: &[HeliacalEvent]) -> String {
+    events
+        .iter()
+        .map(|e| {
+            let mut line = format!(
+                "{} UTC: {} of {}",
+                e.datetime.format("%Y-%m-%d %H:%M"),
+                e.event_type.to_string(),
+                e.object
+            );
+            if let Some(optimum) = e.optimum {
+                line.push_str(&format!(" (best seen {} UTC)", optimum.format("%H:%M")));
+            }
+            format!("{}.", line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format a Davison chart with the midpoint time and place it was cast for
+pub fn format_davison_chart(input: &ChartInput, chart: &Chart) -> String {
+    let mut output = vec![format!(
+        "=== {} ({} UTC, {:.4}, {:.4}) ===",
+        input
+            .name
+            .as_deref()
+            .unwrap_or("Davison")
+            .to_uppercase(),
+        input.datetime.format("%Y-%m-%d %H:%M:%S"),
+        input.latitude,
+        input.longitude
+    )];
+    output.push(String::new());
+    output.push(format_natal_chart(chart));
+    output.join("\n")
+}
+
+/// Format the sorted midpoint list and midpoint trees
+pub fn format_midpoint_report(report: &MidpointReport) -> String {
+    let mut output = vec![format!(
+        "=== MIDPOINTS ({}, orb {:.1}°) ===",
+        report.dial.to_string(),
+        report.orb
+    )];
+    output.push(String::new());
+
+    for midpoint in &report.midpoints {
+        output.push(format!(
+            "{:>7.2}°  {} ({})",
+            report.dial.position(midpoint.longitude),
+            midpoint.label(),
+            format_longitude(midpoint.longitude)
+        ));
+    }
+
+    output.push(String::new());
+    output.push("=== MIDPOINT TREES ===".to_string());
+    output.push(String::new());
+
+    for tree in report.trees.iter().filter(|t| !t.branches.is_empty()) {
+        let branches = tree
+            .branches
+            .iter()
+            .map(|(midpoint, orb)| format!("{} ({:.1}°)", midpoint.label(), orb))
+            .collect::<Vec<_>>()
+            .join(" = ");
+        output.push(format!("{} = {}", tree.focus, branches));
+    }
+
+    output.join("\n")
+}
+
+/// Format transiting or directed points on natal midpoints
+pub fn format_midpoint_contacts(contacts: &[MidpointContact], label: &str) -> String {
+    contacts
+        .iter()
+        .map(|c| {
+            format!(
+                "{} {} ({}) = natal {} ({:.1}°).",
+                label,
+                c.point,
+                format_longitude(c.longitude),
+                c.midpoint.label(),
+                c.orb
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format a harmonic profile as a bar chart, strongest harmonic marked
+pub fn format_harmonic_profile(profile: &[HarmonicScore]) -> String {
+    let strongest = profile
+        .iter()
+        .map(|h| h.score)
+        .fold(0.0_f64, f64::max);
+
+    let mut output = vec!["=== HARMONIC PROFILE ===".to_string(), String::new()];
+    for h in profile {
+        let bar = "#".repeat((h.score * 4.0).round() as usize);
+        output.push(format!(
+            "H{:<3} {:>5.2} ({} conj.) {}{}",
+            h.harmonic,
+            h.score,
+            h.conjunctions,
+            bar,
+            if h.score > 0.0 && h.score == strongest { " <" } else { "" }
+        ));
+    }
+    output.join("\n")
+}
+
+/// Format antiscia and contra-antiscia with the natal points that fall on them
+pub fn format_antiscia(report: &AntisciaReport) -> String {
+    let mut output = vec!["=== ANTISCIA ===".to_string(), String::new()];
+    for point in &report.points {
+        output.push(format!(
+            "{} {}: antiscion {}, contra-antiscion {}.",
+            point.entity,
+            format_longitude(point.longitude),
+            format_longitude(point.antiscion),
+            format_longitude(point.contra_antiscion)
+        ));
+    }
+
+    if !report.contacts.is_empty() {
+        output.push(String::new());
+        output.push("=== ANTISCIA CONTACTS ===".to_string());
+        output.push(String::new());
+        for contact in &report.contacts {
+            output.push(format!(
+                "{} conjunct {} {} ({:.1}°).",
+                contact.aspect.entity1,
+                contact.aspect.entity2,
+                contact.kind.to_string(),
+                contact.aspect.orb
+            ));
+        }
+    }
+
+    output.join("\n")
+}
+
+/// Format the essential dignities table with chart and cusp almutens
+pub fn format_dignities(report: &DignityReport) -> String {
+    let mut output = vec![format!(
+        "=== ESSENTIAL DIGNITIES ({} rulers, {} triplicities, {} chart) ===",
+        report.options.rulership.to_string(),
+        report.options.triplicity.to_string(),
+        report.sect.to_string()
+    )];
+    output.push(String::new());
+    output.push(format!(
+        "{:<9} {:<19} {:<16} {:<9} {:<9} {:<9} {:<9} {:>5}  {}",
+        "Planet", "Position", "Ruler", "Exalt", "Trip", "Term", "Face", "Score", "Dignities"
+    ));
+
+    for planet in &report.planets {
+        let rulers = report
+            .options
+            .rulership
+            .rulers(planet.sign)
+            .iter()
+            .map(|r| r.to_string())
+            .collect::<Vec<_>>()
+            .join("/");
+        let dignities = planet
+            .dignities
+            .iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let dignities = if dignities.is_empty() { "-".to_string() } else { dignities };
+        output.push(format!(
+            "{:<9} {:<19} {:<16} {:<9} {:<9} {:<9} {:<9} {:>+5}  {}",
+            planet.body.to_string(),
+            format_longitude(planet.longitude),
+            rulers,
+            exaltation_ruler(planet.sign)
+                .map(|b| b.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            report
+                .options
+                .triplicity
+                .ruler(planet.sign, report.sect)
+                .to_string(),
+            term_ruler(planet.longitude).to_string(),
+            face_ruler(planet.longitude).to_string(),
+            planet.score,
+            dignities
+        ));
+    }
+
+    output.push(String::new());
+    output.push(format!("Almuten of the chart: {}", report.chart_almuten));
+    output.push(String::new());
+    output.push("=== CUSP ALMUTENS ===".to_string());
+    output.push(String::new());
+    for cusp in &report.cusp_almutens {
+        output.push(format!(
+            "House {:>2} ({}): {}",
+            cusp.house,
+            format_longitude(cusp.longitude),
+            cusp.almuten
+        ));
+    }
+
+    output.join("\n")
+}
+
+/// Format dispositor chains, the dispositor tree and mutual receptions
+pub fn format_dispositors(report: &DispositorReport) -> String {
+    let mut output = vec![format!(
+        "=== DISPOSITORS ({} rulers) ===",
+        report.rulership.to_string()
+    )];
+    output.push(String::new());
+    for chain in &report.chains {
+        let path = chain
+            .chain
+            .iter()
+            .map(|b| b.to_string())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        output.push(format!("{}: {}", path, chain.end));
+    }
+    output.push(String::new());
+    output.push(match report.final_dispositor {
+        Some(body) => format!("Final dispositor: {}", body),
+        None => "No single final dispositor".to_string(),
+    });
+
+    output.push(String::new());
+    output.push("=== DISPOSITOR TREE ===".to_string());
+    output.push(String::new());
+    let mut roots: Vec<(Vec<CelestialBody>, &ChainEnd)> = Vec::new();
+    for chain in &report.chains {
+        let root = match &chain.end {
+            ChainEnd::Final(body) | ChainEnd::Broken(body) => vec![*body],
+            ChainEnd::Cycle(bodies) => bodies.clone(),
+        };
+        if !roots.iter().any(|(r, _)| r.contains(&root[0])) {
+            roots.push((root, &chain.end));
+        }
+    }
+    for (root, end) in &roots {
+        output.push(end.to_string());
+        for body in root {
+            push_disposed(report, *body, root, 1, &mut output);
+        }
+    }
+
+    if !report.receptions.is_empty() {
+        output.push(String::new());
+        output.push("=== MUTUAL RECEPTIONS ===".to_string());
+        output.push(String::new());
+        for reception in &report.receptions {
+            output.push(format!(
+                "{} and {} in {} reception.",
+                reception.first,
+                reception.second,
+                reception.kind.to_string()
+            ));
+        }
+    }
+
+    output.join("\n")
+}
+
+/// Format the chart overview: balance, hemispheres, house types, shape and singletons
+pub fn format_chart_statistics(stats: &ChartStatistics) -> String {
+    let mut output = vec!["Chart Overview:".to_string(), String::new()];
+
+    output.push(format_tallies("Elements", &stats.elements, |k| k.to_string().to_string()));
+    output.push(format_tallies("Modalities", &stats.modalities, |k| {
+        k.to_string().to_string()
+    }));
+    output.push(format_tallies("Hemispheres", &stats.hemispheres, |k| {
+        k.to_string().to_string()
+    }));
+    output.push(format_tallies("Quadrants", &stats.quadrants, |k| format!("Q{}", k)));
+    output.push(format_tallies("Houses", &stats.house_types, |k| k.to_string().to_string()));
+
+    if let Some(shape) = stats.shape {
+        output.push(format!("Shape: {}", shape.to_string()));
+    }
+    for singleton in &stats.singletons {
+        output.push(format!(
+            "Singleton: {} ({}).",
+            singleton.body,
+            singleton.kind
+        ));
+    }
+
+    output.join("\n")
+}
+
+/// Format the ruler of each house cusp, where it is placed, and intercepted signs
+pub fn format_house_rulers(report: &HouseRulerReport) -> String {
+    let mut output = vec!["House Rulers:".to_string(), String::new()];
+
+    for house in &report.houses {
+        let mut line = format!(
+            "House {} ({}): {}",
+            house.house,
+            format_longitude(house.cusp),
+            format_ruler_placement(&house.traditional)
+        );
+        if house.has_modern_ruler() {
+            line.push_str(&format!(
+                "; modern {}",
+                format_ruler_placement(&house.modern)
+            ));
+        }
+        if !house.co_rulers.is_empty() {
+            let co_rulers = house
+                .co_rulers
+                .iter()
+                .map(format_ruler_placement)
+                .collect::<Vec<_>>()
+                .join(", ");
+            let label = if house.co_rulers.len() == 1 { "co-ruler" } else { "co-rulers" };
+            line.push_str(&format!("; {} {}", label, co_rulers));
+        }
+        output.push(format!("{}.", line));
+    }
+
+    if !report.intercepted.is_empty() {
+        let intercepted = report
+            .intercepted
+            .iter()
+            .map(|s| format!("{} in H{}", s.sign.to_string(), s.house))
+            .collect::<Vec<_>>()
+            .join(", ");
+        output.push(String::new());
+        output.push(format!("Intercepted: {}.", intercepted));
+    }
+
+    output.join("\n")
+}
+
 /// Format synastry charts
 pub fn format_synastry_chart(chart1: &Chart, label1: &str, chart2: &Chart, label2: &str) -> String {
     let mut output = Vec::new();
@@ -157,11 +941,11 @@ pub fn format_synastry_chart(chart1: &Chart, label1: &str, chart2: &Chart, label
                         .map(|a| format!("{} {}'s {}", 
                             a.aspect_type.symbol(), 
                             label2,
-                            a.entity2.to_string()))
+                            a.entity2))
                         .collect::<Vec<_>>()
                         .join(", ");
                     
-                    output.push(format!("{}'s {}: {}.", label1, entity1.to_string(), aspect_str));
+                    output.push(format!("{}'s {}: {}.", label1, entity1, aspect_str));
                 }
             }
         }
@@ -186,37 +970,19 @@ fn format_single_chart(chart: &Chart, house_prefix: &str, _label: Option<&str>)
             .unwrap_or_default()
     };
 
-    // Format each body
-    let bodies_to_show = [
-        CelestialBody::Sun,
-        CelestialBody::Moon,
-        CelestialBody::Mercury,
-        CelestialBody::Venus,
-        CelestialBody::Mars,
-        CelestialBody::Jupiter,
-        CelestialBody::Saturn,
-        CelestialBody::Uranus,
-        CelestialBody::Neptune,
-        CelestialBody::Pluto,
-        CelestialBody::TrueNode,
-        CelestialBody::Chiron,
-        CelestialBody::Fortuna,
-        CelestialBody::Vertex,
-    ];
+    // Format each body in the order it was selected for the chart
+    for pos in &chart.positions {
+        let body = pos.body;
+        let entity = AspectEntity::Body(body);
+        let aspects = find_aspects(&entity);
+        let aspect_str = format_aspects(&aspects);
 
-    for body in &bodies_to_show {
-        if let Some(pos) = chart.get_position(*body) {
-            let entity = AspectEntity::Body(*body);
-            let aspects = find_aspects(&entity);
-            let aspect_str = format_aspects(&aspects);
-            
-            let line = if aspect_str.is_empty() {
-                format!("{} {}.", body.to_string(), format_position(pos, house_prefix))
-            } else {
-                format!("{} {}; {}.", body.to_string(), format_position(pos, house_prefix), aspect_str)
-            };
-            output.push(line);
-        }
+        let line = if aspect_str.is_empty() {
+            format!("{} {}.", body, format_position(pos, house_prefix))
+        } else {
+            format!("{} {}; {}.", body, format_position(pos, house_prefix), aspect_str)
+        };
+        output.push(line);
     }
 
     // Format angles
@@ -286,10 +1052,65 @@ fn format_position_short(pos: &Position) -> String {
     )
 }
 
+/// Format a longitude as degree and sign, e.g. "14°32' Leo"
+fn format_longitude(longitude: f64) -> String {
+    let in_sign = longitude % 30.0;
+    format!(
+        "{}°{:02}' {}",
+        in_sign.floor() as u32,
+        ((in_sign.fract() * 60.0).floor() as u32).min(59),
+        ZodiacSign::from_longitude(longitude).to_string()
+    )
+}
+
+/// "Mars in Aquarius H11", or just the planet when it is not in the chart
+fn format_ruler_placement(ruler: &RulerPlacement) -> String {
+    match (ruler.sign, ruler.house) {
+        (Some(sign), Some(house)) => {
+            format!("{} in {} H{}", ruler.body, sign.to_string(), house)
+        }
+        _ => ruler.body.to_string(),
+    }
+}
+
+/// One line of tallies, e.g. "Elements: Fire 5 (4), Earth 2 (2)"
+/// Weight first, point count in brackets when the two differ
+fn format_tallies<K>(label: &str, tallies: &[Tally<K>], name: impl Fn(&K) -> String) -> String {
+    let parts = tallies
+        .iter()
+        .map(|t| {
+            if t.weight == t.count as f64 {
+                format!("{} {}", name(&t.key), t.count)
+            } else {
+                format!("{} {} ({})", name(&t.key), t.weight, t.count)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{}: {}", label, parts)
+}
+
+/// Indented lines for every planet disposed of by `body`, skipping the root planets
+fn push_disposed(
+    report: &DispositorReport,
+    body: CelestialBody,
+    root: &[CelestialBody],
+    depth: usize,
+    output: &mut Vec<String>,
+) {
+    for child in report.disposed_by(body) {
+        if root.contains(&child) {
+            continue;
+        }
+        output.push(format!("{}{}", "    ".repeat(depth), child));
+        push_disposed(report, child, root, depth + 1, output);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::chart::HouseCusps;
+    
 
     #[test]
     fn test_format_position() {
@@ -298,6 +1119,7 @@ mod tests {
             longitude: 71.5, // 11.5 Gemini
             retrograde: false,
             house: 12,
+            horizon: None,
         };
         
         let formatted = format_position(&pos, "H");
@@ -0,0 +1,158 @@
+use crate::aspects::angular_separation;
+use crate::chart::{CelestialBody, Chart, HouseCusps};
+use crate::house_rulers::calculate_house_rulers;
+
+/// Highest harmonic in the profile
+pub const MAX_PROFILE_HARMONIC: u32 = 32;
+
+/// Conjunction orb in the harmonic chart used for profile scoring
+/// (the natal orb shrinks to 1/n of this)
+pub const HARMONIC_PROFILE_ORB: f64 = 8.0;
+
+/// Strength of one harmonic in a chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct HarmonicScore {
+    pub harmonic: u32,
+    pub score: f64, // Sum of conjunction strengths (1 = exact) in the harmonic chart
+    pub conjunctions: usize, // Pairs within orb
+}
+
+/// Harmonic chart: every position and angle multiplied by `n` (mod 360)
+/// Cusps stay natal and each position keeps its natal house
+pub fn calculate_harmonic_chart(chart: &Chart, n: u32) -> Result<Chart, String> {
+    harmonic_chart(chart, n, false)
+}
+
+/// Harmonic chart with the house cusps multiplied as well
+/// Houses are reassigned from the harmonic cusps
+pub fn calculate_harmonic_chart_with_cusps(chart: &Chart, n: u32) -> Result<Chart, String> {
+    harmonic_chart(chart, n, true)
+}
+
+fn harmonic_longitude(longitude: f64, n: u32) -> f64 {
+    (longitude * n as f64).rem_euclid(360.0)
+}
+
+fn harmonic_chart(chart: &Chart, n: u32, include_cusps: bool) -> Result<Chart, String> {
+    if n == 0 {
+        return Err("Harmonic must be at least 1".to_string());
+    }
+
+    let mut harmonic = chart.clone();
+    harmonic.name = Some(match &chart.name {
+        Some(name) => format!("{} H{}", name, n),
+        None => format!("H{}", n),
+    });
+    harmonic.fixed_stars = None;
+
+    for angle in harmonic.angles.iter_mut() {
+        angle.longitude = harmonic_longitude(angle.longitude, n);
+    }
+    if include_cusps {
+        let mut cusps = chart.houses.cusps;
+        for cusp in cusps.iter_mut() {
+            *cusp = harmonic_longitude(*cusp, n);
+        }
+        harmonic.houses = HouseCusps::new(cusps);
+    }
+    for pos in harmonic.positions.iter_mut() {
+        pos.longitude = harmonic_longitude(pos.longitude, n);
+        pos.horizon = None;
+        if include_cusps {
+            pos.house = harmonic.houses.get_house(pos.longitude);
+        }
+    }
+    // Rulers now sit in their harmonic signs; without harmonic cusps the natal
+    // houses no longer describe the chart, so there are no rulers to report
+    harmonic.house_rulers = if include_cusps && chart.house_rulers.is_some() {
+        Some(calculate_house_rulers(&harmonic))
+    } else {
+        None
+    };
+
+    Ok(harmonic)
+}
+
+/// Score harmonics 1 to 32 by how tightly the chart's points conjoin in each harmonic chart
+/// Derived points (lots, Fortuna, Vertex, South Node) are left out: they would
+/// echo the factors they are built from
+pub fn calculate_harmonic_profile(chart: &Chart) -> Vec<HarmonicScore> {
+    let mut longitudes: Vec<f64> = chart
+        .positions
+        .iter()
+        .filter(|p| {
+            !matches!(
+                p.body,
+                CelestialBody::Fortuna
+                    | CelestialBody::Vertex
+                    | CelestialBody::SouthNode
+                    | CelestialBody::Lot(_)
+            )
+        })
+        .map(|p| p.longitude)
+        .collect();
+    longitudes.extend(chart.angles.iter().map(|a| a.longitude));
+
+    (1..=MAX_PROFILE_HARMONIC)
+        .map(|n| {
+            let mut score = 0.0;
+            let mut conjunctions = 0;
+            for (i, a) in longitudes.iter().enumerate() {
+                for b in &longitudes[i + 1..] {
+                    let separation =
+                        angular_separation(harmonic_longitude(*a, n), harmonic_longitude(*b, n));
+                    if separation <= HARMONIC_PROFILE_ORB {
+                        score += 1.0 - separation / HARMONIC_PROFILE_ORB;
+                        conjunctions += 1;
+                    }
+                }
+            }
+            HarmonicScore {
+                harmonic: n,
+                score,
+                conjunctions,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::test_position;
+
+    #[test]
+    fn test_quintile_shows_in_fifth_harmonic() {
+        let mut chart = Chart::new();
+        for (body, longitude) in [
+            (CelestialBody::Sun, 10.0),
+            (CelestialBody::Moon, 82.0), // Quintile
+            (CelestialBody::Mars, 154.0),
+        ] {
+            chart.positions.push(test_position(body, longitude));
+        }
+
+        let h5 = calculate_harmonic_chart(&chart, 5).unwrap();
+        assert!((h5.positions[0].longitude - 50.0).abs() < 1e-9);
+        assert!((h5.positions[1].longitude - 50.0).abs() < 1e-9);
+        assert_eq!(h5.positions[1].house, 1);
+        assert!(calculate_harmonic_chart(&chart, 0).is_err());
+
+        // House rulers follow the harmonic cusps, and are dropped without them
+        chart.house_rulers = Some(calculate_house_rulers(&chart));
+        assert!(calculate_harmonic_chart(&chart, 5).unwrap().house_rulers.is_none());
+        assert!(calculate_harmonic_chart_with_cusps(&chart, 5)
+            .unwrap()
+            .house_rulers
+            .is_some());
+
+        let profile = calculate_harmonic_profile(&chart);
+        assert_eq!(profile.len(), 32);
+        let best = profile
+            .iter()
+            .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap())
+            .unwrap();
+        assert_eq!(best.harmonic % 5, 0);
+        assert_eq!(profile[4].conjunctions, 3);
+    }
+}
@@ -0,0 +1,438 @@
+use crate::chart::CelestialBody;
+use crate::fixed_stars::calculate_fixed_star;
+use crate::sweph::{
+    calculate_jd, east_longitude, jd_to_datetime, swe_heliacal_ut, ZodiacMode,
+    SE_ACRONYCHAL_RISING, SE_ACRONYCHAL_SETTING, SE_EVENING_FIRST, SE_HELFLAG_AVKIND_VR,
+    SE_HELFLAG_HIGH_PRECISION, SE_HELFLAG_OPTICAL_PARAMS, SE_HELIACAL_RISING, SE_HELIACAL_SETTING,
+    SE_MORNING_LAST,
+};
+use chrono::{DateTime, Utc};
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::{c_char, c_int};
+
+/// A planet or a fixed star (Swiss Ephemeris star name, e.g. "Sirius")
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeliacalObject {
+    Planet(CelestialBody),
+    Star(String),
+}
+
+impl fmt::Display for HeliacalObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HeliacalObject::Planet(body) => write!(f, "{}", body),
+            HeliacalObject::Star(name) => f.write_str(name),
+        }
+    }
+}
+
+impl HeliacalObject {
+    /// Name understood by swe_heliacal_ut (Moon to Neptune, or a star)
+    fn swiss_name(&self) -> Result<String, String> {
+        match self {
+            HeliacalObject::Planet(body) => match body {
+                CelestialBody::Moon
+                | CelestialBody::Mercury
+                | CelestialBody::Venus
+                | CelestialBody::Mars
+                | CelestialBody::Jupiter
+                | CelestialBody::Saturn
+                | CelestialBody::Uranus
+                | CelestialBody::Neptune => Ok(body.to_string().to_lowercase()),
+                _ => Err(format!("{} has no heliacal rising or setting", body)),
+            },
+            HeliacalObject::Star(name) => Ok(name.clone()),
+        }
+    }
+
+    /// Phases of visibility that exist for this object
+    pub fn event_types(&self) -> Vec<HeliacalEventType> {
+        match self {
+            // First and last crescent
+            HeliacalObject::Planet(CelestialBody::Moon) => vec![
+                HeliacalEventType::EveningFirst,
+                HeliacalEventType::MorningLast,
+            ],
+            HeliacalObject::Planet(CelestialBody::Mercury | CelestialBody::Venus) => vec![
+                HeliacalEventType::HeliacalRising,
+                HeliacalEventType::HeliacalSetting,
+                HeliacalEventType::EveningFirst,
+                HeliacalEventType::MorningLast,
+            ],
+            _ => vec![
+                HeliacalEventType::HeliacalRising,
+                HeliacalEventType::HeliacalSetting,
+                HeliacalEventType::AcronychalRising,
+                HeliacalEventType::AcronychalSetting,
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeliacalEventType {
+    HeliacalRising,    // Morning first: first visible rising before the Sun
+    HeliacalSetting,   // Evening last: last visible setting after the Sun
+    EveningFirst,      // Inferior planets and Moon
+    MorningLast,       // Inferior planets and Moon
+    AcronychalRising,  // Last visible rising at sunset (superior planets, stars)
+    AcronychalSetting, // First visible setting at sunrise (cosmical setting)
+}
+
+impl HeliacalEventType {
+    pub fn to_string(&self) -> &str {
+        match self {
+            HeliacalEventType::HeliacalRising => "Heliacal Rising",
+            HeliacalEventType::HeliacalSetting => "Heliacal Setting",
+            HeliacalEventType::EveningFirst => "Evening First",
+            HeliacalEventType::MorningLast => "Morning Last",
+            HeliacalEventType::AcronychalRising => "Acronychal Rising",
+            HeliacalEventType::AcronychalSetting => "Acronychal Setting",
+        }
+    }
+
+    fn type_code(&self) -> c_int {
+        match self {
+            HeliacalEventType::HeliacalRising => SE_HELIACAL_RISING,
+            HeliacalEventType::HeliacalSetting => SE_HELIACAL_SETTING,
+            HeliacalEventType::EveningFirst => SE_EVENING_FIRST,
+            HeliacalEventType::MorningLast => SE_MORNING_LAST,
+            HeliacalEventType::AcronychalRising => SE_ACRONYCHAL_RISING,
+            HeliacalEventType::AcronychalSetting => SE_ACRONYCHAL_SETTING,
+        }
+    }
+
+    /// Acronychal events are only defined with the arcus visionis method
+    fn is_acronychal(&self) -> bool {
+        matches!(
+            self,
+            HeliacalEventType::AcronychalRising | HeliacalEventType::AcronychalSetting
+        )
+    }
+}
+
+/// Sky conditions used for the extinction model
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtmosphericConditions {
+    pub pressure: f64,    // mbar (0 = estimate from elevation)
+    pub temperature: f64, // °C
+    pub humidity: f64,    // Relative humidity in %
+    pub visibility: f64,  // Meteorological range in km (>= 1) or total extinction coefficient (< 1)
+}
+
+impl Default for AtmosphericConditions {
+    fn default() -> Self {
+        Self {
+            pressure: 1013.25,
+            temperature: 15.0,
+            humidity: 40.0,
+            visibility: 40.0,
+        }
+    }
+}
+
+/// Optical aid; without one the observer uses the naked eye
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpticalAid {
+    pub binocular: bool,
+    pub magnification: f64,
+    pub aperture: f64,     // mm
+    pub transmission: f64, // 0-1
+}
+
+/// Observer eyesight, height and optics
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObserverConditions {
+    pub age: f64,       // Years (36 = experienced ancient sky watcher)
+    pub snellen: f64,   // Visual acuity (1 = normal)
+    pub elevation: f64, // Eye height above sea level in metres
+    pub optics: Option<OpticalAid>,
+}
+
+impl Default for ObserverConditions {
+    fn default() -> Self {
+        Self {
+            age: 36.0,
+            snellen: 1.0,
+            elevation: 0.0,
+            optics: None,
+        }
+    }
+}
+
+/// Heliacal search settings
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HeliacalOptions {
+    pub atmosphere: AtmosphericConditions,
+    pub observer: ObserverConditions,
+    pub high_precision: bool, // Slower, refines the visibility limit
+}
+
+impl HeliacalOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_atmosphere(mut self, atmosphere: AtmosphericConditions) -> Self {
+        self.atmosphere = atmosphere;
+        self
+    }
+
+    pub fn with_observer(mut self, observer: ObserverConditions) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    pub fn with_high_precision(mut self, high_precision: bool) -> Self {
+        self.high_precision = high_precision;
+        self
+    }
+}
+
+/// One heliacal phenomenon at a location
+#[derive(Debug, Clone)]
+pub struct HeliacalEvent {
+    pub object: HeliacalObject,
+    pub event_type: HeliacalEventType,
+    pub datetime: DateTime<Utc>,              // Beginning of visibility
+    pub optimum: Option<DateTime<Utc>>,       // Best visibility (not given for acronychal events)
+    pub visible_until: Option<DateTime<Utc>>, // End of visibility that morning or evening
+}
+
+/// First heliacal event of the given type after `after`
+pub fn find_heliacal_event(
+    object: &HeliacalObject,
+    event_type: HeliacalEventType,
+    after: DateTime<Utc>,
+    latitude: f64,
+    longitude: f64,
+    options: &HeliacalOptions,
+) -> Result<HeliacalEvent, String> {
+    search_heliacal_event(object, event_type, after, latitude, longitude, options)?.ok_or_else(
+        || {
+            format!(
+                "{} of {} not found: no heliacal date found",
+                event_type.to_string(),
+                object
+            )
+        },
+    )
+}
+
+/// As `find_heliacal_event`, with None when the phase does not occur (rather than an error)
+fn search_heliacal_event(
+    object: &HeliacalObject,
+    event_type: HeliacalEventType,
+    after: DateTime<Utc>,
+    latitude: f64,
+    longitude: f64,
+    options: &HeliacalOptions,
+) -> Result<Option<HeliacalEvent>, String> {
+    if !object.event_types().contains(&event_type) {
+        return Err(format!(
+            "{} does not exist for {}",
+            event_type.to_string(),
+            object
+        ));
+    }
+
+    let name = CString::new(object.swiss_name()?).map_err(|e| e.to_string())?;
+    // Swiss Ephemeris rewrites star names in place, so give it room
+    let mut name_buffer = [0 as c_char; 256];
+    for (dst, src) in name_buffer.iter_mut().zip(name.as_bytes()) {
+        *dst = *src as c_char;
+    }
+
+    let atmosphere = &options.atmosphere;
+    let observer = &options.observer;
+    let mut geopos = [east_longitude(longitude), latitude, observer.elevation];
+    let mut datm = [
+        atmosphere.pressure,
+        atmosphere.temperature,
+        atmosphere.humidity,
+        atmosphere.visibility,
+    ];
+    let mut dobs = [observer.age, observer.snellen, 0.0, 0.0, 0.0, 0.0];
+
+    let mut flags = 0;
+    if let Some(optics) = observer.optics {
+        dobs[2] = if optics.binocular { 1.0 } else { 0.0 };
+        dobs[3] = optics.magnification;
+        dobs[4] = optics.aperture;
+        dobs[5] = optics.transmission;
+        flags |= SE_HELFLAG_OPTICAL_PARAMS;
+    }
+    if options.high_precision {
+        flags |= SE_HELFLAG_HIGH_PRECISION;
+    }
+    if event_type.is_acronychal() {
+        flags |= SE_HELFLAG_AVKIND_VR;
+    }
+
+    let mut dret = [0.0; 50];
+    let mut serr = [0 as c_char; 256];
+    let result = unsafe {
+        swe_heliacal_ut(
+            calculate_jd(&after),
+            geopos.as_mut_ptr(),
+            datm.as_mut_ptr(),
+            dobs.as_mut_ptr(),
+            name_buffer.as_mut_ptr(),
+            event_type.type_code(),
+            flags,
+            dret.as_mut_ptr(),
+            serr.as_mut_ptr(),
+        )
+    };
+    if result < 0 {
+        let message = unsafe { CStr::from_ptr(serr.as_ptr()) }.to_string_lossy();
+        // Swiss Ephemeris returns the same code for a missing phase and a real failure
+        if message.starts_with("no heliacal date found") {
+            return Ok(None);
+        }
+        return Err(format!(
+            "{} of {} failed: {}",
+            event_type.to_string(),
+            object,
+            message.trim()
+        ));
+    }
+
+    let optional = |jd: f64| (jd > 0.0).then(|| jd_to_datetime(jd));
+    Ok(Some(HeliacalEvent {
+        object: object.clone(),
+        event_type,
+        datetime: jd_to_datetime(dret[0]),
+        optimum: optional(dret[1]),
+        visible_until: optional(dret[2]),
+    }))
+}
+
+/// Every heliacal event of an object between `start` and `end`, in time order
+pub fn find_heliacal_events(
+    object: &HeliacalObject,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    latitude: f64,
+    longitude: f64,
+    options: &HeliacalOptions,
+) -> Result<Vec<HeliacalEvent>, String> {
+    if end <= start {
+        return Err("Search end must be after start".to_string());
+    }
+
+    // Bodies without heliacal phases and unknown stars fail here rather than ending
+    // every loop below
+    object.swiss_name()?;
+    if let HeliacalObject::Star(name) = object {
+        calculate_fixed_star(calculate_jd(&start), name, ZodiacMode::Tropical)?;
+    }
+
+    let mut events = Vec::new();
+    for event_type in object.event_types() {
+        let mut after = start;
+        // Not found ends this event type only: Mercury can skip a phase for years,
+        // and a circumpolar star never sets
+        while let Some(event) =
+            search_heliacal_event(object, event_type, after, latitude, longitude, options)?
+        {
+            if event.datetime > end {
+                break;
+            }
+            // Skip ahead a day so the same morning or evening is not found again
+            after = event.datetime + chrono::Duration::days(1);
+            events.push(event);
+        }
+    }
+
+    events.sort_by_key(|e| e.datetime);
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sweph::init_sweph;
+    use chrono::{Datelike, TimeZone};
+
+    #[test]
+    fn test_sirius_heliacal_rising() {
+        init_sweph();
+        // Sirius reappears before dawn at Alexandria in early August
+        let after = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let event = find_heliacal_event(
+            &HeliacalObject::Star("Sirius".to_string()),
+            HeliacalEventType::HeliacalRising,
+            after,
+            31.2,
+            29.9,
+            &HeliacalOptions::new(),
+        )
+        .unwrap();
+
+        assert!(event.datetime.month() == 7 || event.datetime.month() == 8);
+        assert!(find_heliacal_event(
+            &HeliacalObject::Planet(CelestialBody::Sun),
+            HeliacalEventType::HeliacalRising,
+            after,
+            31.2,
+            29.9,
+            &HeliacalOptions::new(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_circumpolar_star_has_no_setting() {
+        init_sweph();
+        // Capella never sets at Stockholm, so its setting phases are not found
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let capella = HeliacalObject::Star("Capella".to_string());
+        let options = HeliacalOptions::new();
+        assert!(find_heliacal_event(
+            &capella,
+            HeliacalEventType::HeliacalSetting,
+            start,
+            59.3,
+            18.1,
+            &options
+        )
+        .is_err());
+        let events = find_heliacal_events(&capella, start, end, 59.3, 18.1, &options).unwrap();
+        assert!(events
+            .iter()
+            .all(|e| e.event_type != HeliacalEventType::HeliacalSetting));
+
+        assert!(find_heliacal_events(
+            &HeliacalObject::Planet(CelestialBody::Sun),
+            start,
+            end,
+            59.3,
+            18.1,
+            &options,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_unknown_star_is_an_error() {
+        init_sweph();
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let unknown = HeliacalObject::Star("NoSuchStarXYZ".to_string());
+        let options = HeliacalOptions::new();
+        assert!(find_heliacal_events(&unknown, start, end, 31.2, 29.9, &options).is_err());
+        assert!(find_heliacal_event(
+            &unknown,
+            HeliacalEventType::HeliacalRising,
+            start,
+            31.2,
+            29.9,
+            &options,
+        )
+        .unwrap_err()
+        .contains("not found"));
+    }
+}
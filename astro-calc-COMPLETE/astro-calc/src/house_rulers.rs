@@ -0,0 +1,170 @@
+use crate::chart::{CelestialBody, Chart, ZodiacSign};
+use serde::{Deserialize, Serialize};
+
+/// A ruling planet and where it stands in the chart
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RulerPlacement {
+    pub body: CelestialBody,
+    pub sign: Option<ZodiacSign>, // None when the ruler is not among the chart's bodies
+    pub house: Option<u8>,
+}
+
+/// A sign with no cusp in it, swallowed whole by one house
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InterceptedSign {
+    pub sign: ZodiacSign,
+    pub house: u8,
+}
+
+/// Rulers of one house: the cusp sign's rulers plus those of any sign intercepted in it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HouseRuler {
+    pub house: u8,
+    pub cusp: f64,
+    pub sign: ZodiacSign,
+    pub traditional: RulerPlacement,
+    pub modern: RulerPlacement, // Same planet as `traditional` outside Scorpio, Aquarius and Pisces
+    pub co_rulers: Vec<RulerPlacement>, // Rulers of intercepted signs, traditional first
+}
+
+impl HouseRuler {
+    /// True when the modern ruler differs and the traditional one is its co-ruler
+    pub fn has_modern_ruler(&self) -> bool {
+        self.modern.body != self.traditional.body
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HouseRulerReport {
+    pub houses: Vec<HouseRuler>,
+    pub intercepted: Vec<InterceptedSign>,
+}
+
+impl HouseRulerReport {
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    pub fn get_house(&self, house: u8) -> Option<&HouseRuler> {
+        self.houses.iter().find(|h| h.house == house)
+    }
+}
+
+fn placement(chart: &Chart, body: CelestialBody) -> RulerPlacement {
+    let position = chart.get_position(body);
+    RulerPlacement {
+        body,
+        sign: position.map(|p| p.sign()),
+        house: position.map(|p| p.house),
+    }
+}
+
+/// Signs that hold no house cusp, with the house they fall in
+pub fn intercepted_signs(chart: &Chart) -> Vec<InterceptedSign> {
+    let cusp_signs: Vec<ZodiacSign> = chart
+        .houses
+        .cusps
+        .iter()
+        .map(|c| ZodiacSign::from_longitude(*c))
+        .collect();
+    (0..12)
+        .map(|i| ZodiacSign::from_longitude(i as f64 * 30.0))
+        .filter(|sign| !cusp_signs.contains(sign))
+        .map(|sign| InterceptedSign {
+            sign,
+            // The whole sign sits in one house, so its middle tells which
+            house: chart.houses.get_house(sign.index() as f64 * 30.0 + 15.0),
+        })
+        .collect()
+}
+
+/// Ruler of every house cusp and where it is placed, with intercepted signs
+pub fn calculate_house_rulers(chart: &Chart) -> HouseRulerReport {
+    let intercepted = intercepted_signs(chart);
+
+    let houses = chart
+        .houses
+        .cusps
+        .iter()
+        .enumerate()
+        .map(|(i, cusp)| {
+            let house = i as u8 + 1;
+            let sign = ZodiacSign::from_longitude(*cusp);
+            let mut co_rulers = Vec::new();
+            for s in intercepted.iter().filter(|s| s.house == house) {
+                co_rulers.push(placement(chart, s.sign.traditional_ruler()));
+                if s.sign.modern_ruler() != s.sign.traditional_ruler() {
+                    co_rulers.push(placement(chart, s.sign.modern_ruler()));
+                }
+            }
+            HouseRuler {
+                house,
+                cusp: *cusp,
+                sign,
+                traditional: placement(chart, sign.traditional_ruler()),
+                modern: placement(chart, sign.modern_ruler()),
+                co_rulers,
+            }
+        })
+        .collect();
+
+    HouseRulerReport {
+        houses,
+        intercepted,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::{test_position, HouseCusps, Position};
+
+    #[test]
+    fn test_rulers_and_interceptions() {
+        let mut chart = Chart::new();
+        // Wide 1st and 7th houses at a high latitude: Taurus and Scorpio intercepted
+        chart.houses = HouseCusps::new([
+            20.0, 80.0, 100.0, 120.0, 140.0, 170.0, 200.0, 260.0, 280.0, 300.0, 320.0, 350.0,
+        ]);
+        for (body, longitude) in [
+            (CelestialBody::Mars, 45.0),
+            (CelestialBody::Venus, 215.0),
+            (CelestialBody::Pluto, 130.0),
+        ] {
+            chart.positions.push(Position {
+                house: chart.houses.get_house(longitude),
+                ..test_position(body, longitude)
+            });
+        }
+
+        let report = calculate_house_rulers(&chart);
+        assert_eq!(
+            report.intercepted,
+            vec![
+                InterceptedSign {
+                    sign: ZodiacSign::Taurus,
+                    house: 1
+                },
+                InterceptedSign {
+                    sign: ZodiacSign::Scorpio,
+                    house: 7
+                },
+            ]
+        );
+
+        let first = report.get_house(1).unwrap();
+        assert_eq!(first.traditional.body, CelestialBody::Mars);
+        assert_eq!(first.traditional.house, Some(1));
+        assert!(!first.has_modern_ruler());
+        assert_eq!(first.co_rulers[0].body, CelestialBody::Venus);
+        assert_eq!(first.co_rulers[0].sign, Some(ZodiacSign::Scorpio));
+
+        let seventh = report.get_house(7).unwrap();
+        let co_rulers: Vec<CelestialBody> = seventh.co_rulers.iter().map(|r| r.body).collect();
+        assert_eq!(co_rulers, vec![CelestialBody::Mars, CelestialBody::Pluto]);
+        assert_eq!(seventh.co_rulers[1].house, Some(4));
+        assert_eq!(report.get_house(3).unwrap().traditional.sign, None); // Moon not in chart
+
+        assert!(report.to_json().unwrap().contains("\"intercepted\""));
+    }
+}
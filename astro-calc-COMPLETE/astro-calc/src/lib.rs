@@ -2,8 +2,50 @@ pub mod chart;
 pub mod aspects;
 pub mod formatter;
 pub mod sweph;
+pub mod fixed_stars;
+pub mod lots;
+pub mod progressions;
+pub mod directions;
+pub mod returns;
+pub mod calendar;
+pub mod transit_search;
+pub mod stations;
+pub mod moon;
+pub mod eclipses;
+pub mod rise_set;
+pub mod planetary_hours;
+pub mod heliacal;
+pub mod composite;
+pub mod midpoints;
+pub mod harmonics;
+pub mod antiscia;
+pub mod dignities;
+pub mod dispositors;
+pub mod statistics;
+pub mod house_rulers;
 
 pub use chart::*;
 pub use aspects::*;
 pub use formatter::*;
 pub use sweph::*;
+pub use fixed_stars::*;
+pub use lots::*;
+pub use progressions::*;
+pub use directions::*;
+pub use returns::*;
+pub use calendar::*;
+pub use transit_search::*;
+pub use stations::*;
+pub use moon::*;
+pub use eclipses::*;
+pub use rise_set::*;
+pub use planetary_hours::*;
+pub use heliacal::*;
+pub use composite::*;
+pub use midpoints::*;
+pub use harmonics::*;
+pub use antiscia::*;
+pub use dignities::*;
+pub use dispositors::*;
+pub use statistics::*;
+pub use house_rulers::*;
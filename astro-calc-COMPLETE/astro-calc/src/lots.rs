@@ -0,0 +1,336 @@
+use crate::chart::{AnglePoint, CelestialBody, Chart, ZodiacSign};
+use serde::{Deserialize, Serialize};
+
+/// Built-in Arabic Parts / Hellenistic lots
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Lot {
+    Fortune, // Stored in charts as CelestialBody::Fortuna
+    Spirit,
+    Eros,
+    Necessity,
+    Courage,
+    Victory,
+    Nemesis,
+    Marriage,
+    Father,
+    Mother,
+}
+
+impl Lot {
+    pub fn to_string(&self) -> &str {
+        match self {
+            Lot::Fortune => "Fortune",
+            Lot::Spirit => "Spirit",
+            Lot::Eros => "Eros",
+            Lot::Necessity => "Necessity",
+            Lot::Courage => "Courage",
+            Lot::Victory => "Victory",
+            Lot::Nemesis => "Nemesis",
+            Lot::Marriage => "Marriage",
+            Lot::Father => "Father",
+            Lot::Mother => "Mother",
+        }
+    }
+
+    /// Formula text for this lot (Paulus Alexandrinus / Valens)
+    pub fn formula_text(&self) -> &str {
+        match self {
+            Lot::Fortune => "ASC + Moon - Sun",
+            Lot::Spirit => "ASC + Sun - Moon",
+            Lot::Eros => "ASC + Venus - Spirit",
+            Lot::Necessity => "ASC + Fortune - Mercury",
+            Lot::Courage => "ASC + Fortune - Mars",
+            Lot::Victory => "ASC + Jupiter - Spirit",
+            Lot::Nemesis => "ASC + Fortune - Saturn",
+            Lot::Marriage => "ASC + Venus - Saturn",
+            Lot::Father => "ASC + Saturn - Sun",
+            Lot::Mother => "ASC + Moon - Venus",
+        }
+    }
+
+    /// Parsed formula, including day/night reversal where the tradition uses it
+    pub fn formula(&self) -> LotFormula {
+        let formula = LotFormula::parse(self.formula_text()).expect("built-in lot formula");
+        match self {
+            Lot::Marriage => formula,
+            _ => formula.reversed_at_night(),
+        }
+    }
+
+    /// The body this lot is stored as in `Chart.positions`
+    pub fn body(&self) -> CelestialBody {
+        match self {
+            Lot::Fortune => CelestialBody::Fortuna,
+            lot => CelestialBody::Lot(*lot),
+        }
+    }
+
+    /// All catalogue lots except Fortune (which is always available as Fortuna)
+    pub fn all() -> Vec<Self> {
+        vec![
+            Lot::Spirit,
+            Lot::Eros,
+            Lot::Necessity,
+            Lot::Courage,
+            Lot::Victory,
+            Lot::Nemesis,
+            Lot::Marriage,
+            Lot::Father,
+            Lot::Mother,
+        ]
+    }
+}
+
+/// Something a lot formula can refer to
+#[derive(Debug, Clone, PartialEq)]
+pub enum LotOperand {
+    Body(CelestialBody),
+    Lot(Lot),
+    Ascendant,
+    Midheaven,
+    Descendant,
+    ImumCoeli,
+    Cusp(u8),               // House cusp 1-12 ("H7")
+    Ruler(Box<LotOperand>), // Traditional ruler of the sign the operand is in ("Ruler(H7)")
+}
+
+impl LotOperand {
+    fn parse(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        let lower = text.to_lowercase();
+
+        for prefix in ["ruler(", "lord("] {
+            if lower.starts_with(prefix) && lower.ends_with(')') {
+                let inner = &text[prefix.len()..text.len() - 1];
+                return Ok(LotOperand::Ruler(Box::new(LotOperand::parse(inner)?)));
+            }
+        }
+
+        match lower.as_str() {
+            "asc" | "ac" | "ascendant" => return Ok(LotOperand::Ascendant),
+            "mc" | "midheaven" => return Ok(LotOperand::Midheaven),
+            "dsc" | "dc" | "descendant" => return Ok(LotOperand::Descendant),
+            "ic" => return Ok(LotOperand::ImumCoeli),
+            "fortuna" => return Ok(LotOperand::Lot(Lot::Fortune)),
+            _ => {}
+        }
+
+        if let Some(number) = lower.strip_prefix('h') {
+            if let Ok(house) = number.parse::<u8>() {
+                if (1..=12).contains(&house) {
+                    return Ok(LotOperand::Cusp(house));
+                }
+                return Err(format!("House cusp out of range: {}", text));
+            }
+        }
+
+        let lot_name = lower.strip_prefix("lot of ").unwrap_or(&lower);
+        let mut lots = Lot::all();
+        lots.push(Lot::Fortune);
+        if let Some(lot) = lots
+            .into_iter()
+            .find(|l| l.to_string().to_lowercase() == lot_name)
+        {
+            return Ok(LotOperand::Lot(lot));
+        }
+
+        let mut bodies = CelestialBody::all();
+        bodies.extend(CelestialBody::additional());
+        bodies
+            .into_iter()
+            .find(|b| b.to_string().to_lowercase() == lower)
+            .map(LotOperand::Body)
+            .ok_or_else(|| format!("Unknown lot operand: {}", text))
+    }
+}
+
+/// A parsed lot formula: a sum of added and subtracted operands
+#[derive(Debug, Clone, PartialEq)]
+pub struct LotFormula {
+    pub day: Vec<(f64, LotOperand)>, // (+1.0 or -1.0, operand)
+    pub night: Option<Vec<(f64, LotOperand)>>,
+}
+
+impl LotFormula {
+    /// Parse "ASC + Venus - Sun", or "day formula | night formula"
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut parts = text.split('|');
+        let day = parse_terms(parts.next().unwrap_or(""))?;
+        let night = match parts.next() {
+            Some(night) => Some(parse_terms(night)?),
+            None => None,
+        };
+        if parts.next().is_some() {
+            return Err(format!("Too many '|' in lot formula: {}", text));
+        }
+        Ok(Self { day, night })
+    }
+
+    /// Swap added and subtracted operands (after the first) for night births
+    pub fn reversed_at_night(mut self) -> Self {
+        let night = self
+            .day
+            .iter()
+            .enumerate()
+            .map(|(i, (sign, operand))| {
+                if i == 0 {
+                    (*sign, operand.clone())
+                } else {
+                    (-sign, operand.clone())
+                }
+            })
+            .collect();
+        self.night = Some(night);
+        self
+    }
+
+    fn terms(&self, is_day: bool) -> &[(f64, LotOperand)] {
+        match (&self.night, is_day) {
+            (Some(night), false) => night,
+            _ => &self.day,
+        }
+    }
+}
+
+fn parse_terms(text: &str) -> Result<Vec<(f64, LotOperand)>, String> {
+    let mut terms = Vec::new();
+    let mut sign = 1.0;
+    let mut current = String::new();
+
+    for c in text.chars() {
+        if c == '+' || c == '-' {
+            if !current.trim().is_empty() {
+                terms.push((sign, LotOperand::parse(&current)?));
+            } else if !terms.is_empty() {
+                return Err(format!("Missing operand in lot formula: {}", text));
+            }
+            current.clear();
+            sign = if c == '+' { 1.0 } else { -1.0 };
+        } else {
+            current.push(c);
+        }
+    }
+
+    if current.trim().is_empty() {
+        return Err(format!("Incomplete lot formula: {}", text));
+    }
+    terms.push((sign, LotOperand::parse(&current)?));
+    Ok(terms)
+}
+
+/// Chart data a lot formula is evaluated against
+pub struct LotContext<'a> {
+    chart: &'a Chart,
+    is_day: bool,
+    fallback: Option<&'a dyn Fn(CelestialBody) -> Result<f64, String>>,
+}
+
+impl<'a> LotContext<'a> {
+    pub fn new(chart: &'a Chart, is_day: bool) -> Self {
+        Self {
+            chart,
+            is_day,
+            fallback: None,
+        }
+    }
+
+    /// Calculate bodies that aren't part of the chart's selection on demand
+    pub(crate) fn with_fallback(
+        mut self,
+        fallback: &'a dyn Fn(CelestialBody) -> Result<f64, String>,
+    ) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+
+    /// Evaluate a formula to an ecliptic longitude (0-360)
+    pub fn evaluate(&self, formula: &LotFormula) -> Result<f64, String> {
+        let mut total = 0.0;
+        for (sign, operand) in formula.terms(self.is_day) {
+            total += sign * self.resolve(operand)?;
+        }
+        Ok(total.rem_euclid(360.0))
+    }
+
+    fn resolve(&self, operand: &LotOperand) -> Result<f64, String> {
+        let angle = |point: AnglePoint| {
+            self.chart
+                .get_angle(point)
+                .map(|a| a.longitude)
+                .ok_or_else(|| format!("{} is not in the chart", point.to_string()))
+        };
+
+        match operand {
+            LotOperand::Body(body) => self.body_longitude(*body),
+            LotOperand::Lot(lot) => self.evaluate(&lot.formula()),
+            LotOperand::Ascendant => angle(AnglePoint::Ascendant),
+            LotOperand::Midheaven => angle(AnglePoint::Midheaven),
+            LotOperand::Descendant => Ok((angle(AnglePoint::Ascendant)? + 180.0) % 360.0),
+            LotOperand::ImumCoeli => Ok((angle(AnglePoint::Midheaven)? + 180.0) % 360.0),
+            LotOperand::Cusp(house) => Ok(self.chart.houses.cusps[(*house - 1) as usize]),
+            LotOperand::Ruler(inner) => {
+                let sign = ZodiacSign::from_longitude(self.resolve(inner)?);
+                self.body_longitude(sign.traditional_ruler())
+            }
+        }
+    }
+
+    fn body_longitude(&self, body: CelestialBody) -> Result<f64, String> {
+        if let Some(pos) = self.chart.get_position(body) {
+            return Ok(pos.longitude);
+        }
+        match self.fallback {
+            Some(fallback) => fallback(body),
+            None => Err(format!("{} is not in the chart", body)),
+        }
+    }
+}
+
+/// Evaluate a formula such as "ASC + Venus - Sun" against a calculated chart
+pub fn calculate_lot(chart: &Chart, formula: &str, is_day: bool) -> Result<f64, String> {
+    let formula = LotFormula::parse(formula)?;
+    LotContext::new(chart, is_day).evaluate(&formula)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::{test_position, AnglePosition};
+
+    fn test_chart() -> Chart {
+        let mut chart = Chart::new();
+        chart.angles.push(AnglePosition {
+            angle: AnglePoint::Ascendant,
+            longitude: 100.0,
+        });
+        for (body, longitude) in [(CelestialBody::Sun, 10.0), (CelestialBody::Moon, 50.0)] {
+            chart.positions.push(test_position(body, longitude));
+        }
+        chart
+    }
+
+    #[test]
+    fn test_fortune_and_spirit_reverse_at_night() {
+        let chart = test_chart();
+        let day = LotContext::new(&chart, true);
+        let night = LotContext::new(&chart, false);
+
+        assert!((day.evaluate(&Lot::Fortune.formula()).unwrap() - 140.0).abs() < 1e-9);
+        assert!((night.evaluate(&Lot::Fortune.formula()).unwrap() - 60.0).abs() < 1e-9);
+        assert!((day.evaluate(&Lot::Spirit.formula()).unwrap() - 60.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_formula_parsing() {
+        let chart = test_chart();
+        assert!(
+            (calculate_lot(&chart, "AC + Moon - Sun | ASC + Sun - Moon", false).unwrap() - 60.0)
+                .abs()
+                < 1e-9
+        );
+        // Ruler of the 10 Aries Sun is Mars, which is not in the chart
+        assert!(calculate_lot(&chart, "ASC + Ruler(Sun) - Moon", true).is_err());
+        assert!(calculate_lot(&chart, "ASC + Banana", true).is_err());
+        assert!(calculate_lot(&chart, "ASC + H13", true).is_err());
+    }
+}
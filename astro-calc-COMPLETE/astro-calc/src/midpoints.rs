@@ -0,0 +1,258 @@
+use crate::aspects::AspectEntity;
+use crate::chart::Chart;
+use crate::composite::near_midpoint;
+use serde::{Deserialize, Serialize};
+
+/// Default orb for a point on a midpoint (Ebertin)
+pub const MIDPOINT_ORB: f64 = 1.5;
+
+/// Dial used to compare midpoints: hard aspects fold onto conjunctions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Dial {
+    Full,   // 360°: conjunction only
+    Dial90, // Conjunction, square, opposition
+    Dial45, // Adds semi-square and sesquiquadrate
+}
+
+impl Dial {
+    pub fn to_string(&self) -> &str {
+        match self {
+            Dial::Full => "360° dial",
+            Dial::Dial90 => "90° dial",
+            Dial::Dial45 => "45° dial",
+        }
+    }
+
+    pub fn modulus(&self) -> f64 {
+        match self {
+            Dial::Full => 360.0,
+            Dial::Dial90 => 90.0,
+            Dial::Dial45 => 45.0,
+        }
+    }
+
+    /// Position of a longitude on this dial
+    pub fn position(&self, longitude: f64) -> f64 {
+        longitude.rem_euclid(self.modulus())
+    }
+
+    /// Distance between two longitudes once folded onto this dial
+    pub fn separation(&self, a: f64, b: f64) -> f64 {
+        let modulus = self.modulus();
+        let diff = (a - b).rem_euclid(modulus);
+        diff.min(modulus - diff)
+    }
+}
+
+/// Near midpoint of two chart points
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Midpoint {
+    pub first: AspectEntity,
+    pub second: AspectEntity,
+    pub longitude: f64,
+}
+
+impl Midpoint {
+    /// Ebertin notation, e.g. "Sun/Moon"
+    pub fn label(&self) -> String {
+        format!("{}/{}", self.first, self.second)
+    }
+
+    pub fn involves(&self, entity: &AspectEntity) -> bool {
+        self.first == *entity || self.second == *entity
+    }
+}
+
+/// A point sitting on a midpoint within orb on the dial
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MidpointContact {
+    pub point: AspectEntity,
+    pub longitude: f64,
+    pub midpoint: Midpoint,
+    pub orb: f64,
+}
+
+/// Every midpoint a point activates: "Sun = Moon/Mars = Venus/ASC"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MidpointTree {
+    pub focus: AspectEntity,
+    pub longitude: f64,
+    pub branches: Vec<(Midpoint, f64)>, // Midpoint and orb, closest first
+}
+
+/// All midpoints of a chart sorted by dial position, plus the tree for each point
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MidpointReport {
+    pub dial: Dial,
+    pub orb: f64,
+    pub midpoints: Vec<Midpoint>,
+    pub trees: Vec<MidpointTree>,
+}
+
+impl MidpointReport {
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+}
+
+/// Every pairwise midpoint of a chart's positions and angles
+pub fn calculate_midpoints(chart: &Chart) -> Vec<Midpoint> {
+    let points = chart.points();
+    let mut midpoints = Vec::new();
+    for (i, (first, lon1)) in points.iter().enumerate() {
+        for (second, lon2) in &points[i + 1..] {
+            midpoints.push(Midpoint {
+                first: first.clone(),
+                second: second.clone(),
+                longitude: near_midpoint(*lon1, *lon2),
+            });
+        }
+    }
+    midpoints
+}
+
+/// Sort midpoints by their position on the dial (Ebertin sorted list)
+pub fn sort_midpoints_by_dial(midpoints: &mut [Midpoint], dial: Dial) {
+    midpoints.sort_by(|a, b| {
+        dial.position(a.longitude)
+            .partial_cmp(&dial.position(b.longitude))
+            .unwrap()
+    });
+}
+
+/// Points from `points` sitting on any of `midpoints` within `orb` on the dial
+/// A point never activates a midpoint it is part of
+pub fn find_midpoint_contacts(
+    points: &[(AspectEntity, f64)],
+    midpoints: &[Midpoint],
+    dial: Dial,
+    orb: f64,
+) -> Vec<MidpointContact> {
+    let mut contacts = Vec::new();
+    for (point, longitude) in points {
+        for midpoint in midpoints {
+            if midpoint.involves(point) {
+                continue;
+            }
+            let distance = dial.separation(*longitude, midpoint.longitude);
+            if distance <= orb {
+                contacts.push(MidpointContact {
+                    point: point.clone(),
+                    longitude: *longitude,
+                    midpoint: midpoint.clone(),
+                    orb: distance,
+                });
+            }
+        }
+    }
+    contacts.sort_by(|a, b| a.orb.partial_cmp(&b.orb).unwrap());
+    contacts
+}
+
+/// Transiting or directed points (`moving`) on natal midpoints
+/// Same-named points are different factors here, so natal Sun/Moon can be hit by transiting Sun
+pub fn calculate_midpoint_contacts(
+    natal: &Chart,
+    moving: &Chart,
+    dial: Dial,
+    orb: f64,
+) -> Vec<MidpointContact> {
+    let midpoints = calculate_midpoints(natal);
+    let mut contacts = Vec::new();
+    for (point, longitude) in moving.points() {
+        for midpoint in &midpoints {
+            let distance = dial.separation(longitude, midpoint.longitude);
+            if distance <= orb {
+                contacts.push(MidpointContact {
+                    point: point.clone(),
+                    longitude,
+                    midpoint: midpoint.clone(),
+                    orb: distance,
+                });
+            }
+        }
+    }
+    contacts.sort_by(|a, b| a.orb.partial_cmp(&b.orb).unwrap());
+    contacts
+}
+
+/// JSON array of midpoint contacts (transits or directions)
+pub fn midpoint_contacts_to_json(contacts: &[MidpointContact]) -> Result<String, String> {
+    serde_json::to_string_pretty(contacts).map_err(|e| e.to_string())
+}
+
+/// Midpoint tree for every point of the chart
+pub fn calculate_midpoint_trees(chart: &Chart, dial: Dial, orb: f64) -> Vec<MidpointTree> {
+    let midpoints = calculate_midpoints(chart);
+    chart.points()
+        .into_iter()
+        .map(|(focus, longitude)| {
+            let branches =
+                find_midpoint_contacts(&[(focus.clone(), longitude)], &midpoints, dial, orb)
+                    .into_iter()
+                    .map(|c| (c.midpoint, c.orb))
+                    .collect();
+            MidpointTree {
+                focus,
+                longitude,
+                branches,
+            }
+        })
+        .collect()
+}
+
+/// Sorted midpoint list and trees for a chart
+pub fn calculate_midpoint_report(chart: &Chart, dial: Dial, orb: f64) -> MidpointReport {
+    let mut midpoints = calculate_midpoints(chart);
+    sort_midpoints_by_dial(&mut midpoints, dial);
+    MidpointReport {
+        dial,
+        orb,
+        midpoints,
+        trees: calculate_midpoint_trees(chart, dial, orb),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::{test_position as position, CelestialBody};
+
+    #[test]
+    fn test_midpoint_tree_on_dials() {
+        let mut chart = Chart::new();
+        chart.positions = vec![
+            position(CelestialBody::Sun, 10.0),
+            position(CelestialBody::Moon, 50.0),
+            position(CelestialBody::Mars, 30.5),
+            position(CelestialBody::Venus, 120.0), // Square Sun/Moon on the 90° dial
+        ];
+
+        let report = calculate_midpoint_report(&chart, Dial::Full, MIDPOINT_ORB);
+        assert_eq!(report.midpoints.len(), 6);
+        let mars = AspectEntity::Body(CelestialBody::Mars);
+        let tree = report.trees.iter().find(|t| t.focus == mars).unwrap();
+        assert_eq!(tree.branches.len(), 1);
+        assert_eq!(tree.branches[0].0.label(), "Sun/Moon");
+        assert!((tree.branches[0].1 - 0.5).abs() < 1e-9);
+
+        let venus = AspectEntity::Body(CelestialBody::Venus);
+        let full = calculate_midpoint_trees(&chart, Dial::Full, MIDPOINT_ORB);
+        let dial90 = calculate_midpoint_trees(&chart, Dial::Dial90, MIDPOINT_ORB);
+        assert!(full
+            .iter()
+            .find(|t| t.focus == venus)
+            .unwrap()
+            .branches
+            .is_empty());
+        assert!(dial90
+            .iter()
+            .find(|t| t.focus == venus)
+            .unwrap()
+            .branches
+            .iter()
+            .any(|(m, _)| m.label() == "Sun/Moon"));
+
+        assert!(report.to_json().unwrap().contains("\"dial\": \"Full\""));
+    }
+}
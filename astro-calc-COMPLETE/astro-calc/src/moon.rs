@@ -0,0 +1,405 @@
+use crate::aspects::AspectType;
+use crate::calendar::{events_to_ics, CalendarEvent};
+use crate::chart::{CelestialBody, ZodiacSign};
+use crate::returns::longitude_difference;
+use crate::stations::{calculate_station_calendar, SignIngress, StationCalendarOptions};
+use crate::sweph::{calculate_jd, calculate_planet_speed, jd_to_datetime, ZodiacMode};
+use chrono::{DateTime, Duration, Utc};
+
+/// Sampling step (days) for lunations and Moon aspects
+const MOON_STEP: f64 = 0.25;
+
+/// The eight phases of the lunation cycle, each 45° of Sun-Moon elongation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoonPhase {
+    NewMoon,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    FullMoon,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    pub fn to_string(&self) -> &str {
+        match self {
+            MoonPhase::NewMoon => "New Moon",
+            MoonPhase::WaxingCrescent => "Waxing Crescent",
+            MoonPhase::FirstQuarter => "First Quarter",
+            MoonPhase::WaxingGibbous => "Waxing Gibbous",
+            MoonPhase::FullMoon => "Full Moon",
+            MoonPhase::WaningGibbous => "Waning Gibbous",
+            MoonPhase::LastQuarter => "Last Quarter",
+            MoonPhase::WaningCrescent => "Waning Crescent",
+        }
+    }
+
+    /// Phase for a Sun-Moon elongation (phases begin at the exact lunation)
+    pub fn from_angle(angle: f64) -> Self {
+        match (angle.rem_euclid(360.0) / 45.0).floor() as u32 {
+            0 => MoonPhase::NewMoon,
+            1 => MoonPhase::WaxingCrescent,
+            2 => MoonPhase::FirstQuarter,
+            3 => MoonPhase::WaxingGibbous,
+            4 => MoonPhase::FullMoon,
+            5 => MoonPhase::WaningGibbous,
+            6 => MoonPhase::LastQuarter,
+            _ => MoonPhase::WaningCrescent,
+        }
+    }
+}
+
+/// Phase of the Moon at an instant
+#[derive(Debug, Clone, Copy)]
+pub struct MoonPhaseInfo {
+    pub angle: f64, // Moon minus Sun, 0-360
+    pub phase: MoonPhase,
+    pub illumination: f64, // Illuminated fraction of the disc, 0-1
+}
+
+/// Exact New, Full and quarter Moons
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LunationKind {
+    NewMoon,
+    FirstQuarter,
+    FullMoon,
+    LastQuarter,
+}
+
+impl LunationKind {
+    pub fn to_string(&self) -> &str {
+        match self {
+            LunationKind::NewMoon => "New Moon",
+            LunationKind::FirstQuarter => "First Quarter Moon",
+            LunationKind::FullMoon => "Full Moon",
+            LunationKind::LastQuarter => "Last Quarter Moon",
+        }
+    }
+
+    fn angle(&self) -> f64 {
+        match self {
+            LunationKind::NewMoon => 0.0,
+            LunationKind::FirstQuarter => 90.0,
+            LunationKind::FullMoon => 180.0,
+            LunationKind::LastQuarter => 270.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Lunation {
+    pub kind: LunationKind,
+    pub datetime: DateTime<Utc>,
+    pub longitude: f64, // Moon's longitude in the calendar's frame
+}
+
+/// The Moon between its last major aspect in a sign and its next ingress
+#[derive(Debug, Clone)]
+pub struct VoidOfCourse {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,                               // Sign ingress
+    pub sign: ZodiacSign,                                 // Sign the Moon is leaving
+    pub last_aspect: Option<(AspectType, CelestialBody)>, // None = no aspect in the whole sign
+}
+
+/// Moon calendar settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoonOptions {
+    pub aspects: Vec<AspectType>, // Aspects that end a void-of-course period
+    pub bodies: Vec<CelestialBody>, // Bodies the Moon aspects
+    pub mode: ZodiacMode,         // Frame for signs and reported degrees
+}
+
+impl Default for MoonOptions {
+    fn default() -> Self {
+        Self {
+            aspects: vec![
+                AspectType::Conjunction,
+                AspectType::Sextile,
+                AspectType::Square,
+                AspectType::Trine,
+                AspectType::Opposition,
+            ],
+            bodies: vec![
+                CelestialBody::Sun,
+                CelestialBody::Mercury,
+                CelestialBody::Venus,
+                CelestialBody::Mars,
+                CelestialBody::Jupiter,
+                CelestialBody::Saturn,
+                CelestialBody::Uranus,
+                CelestialBody::Neptune,
+                CelestialBody::Pluto,
+            ],
+            mode: ZodiacMode::Sidereal,
+        }
+    }
+}
+
+impl MoonOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_aspects(mut self, aspects: Vec<AspectType>) -> Self {
+        self.aspects = aspects;
+        self
+    }
+
+    pub fn with_bodies(mut self, bodies: Vec<CelestialBody>) -> Self {
+        self.bodies = bodies;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: ZodiacMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MoonCalendar {
+    pub lunations: Vec<Lunation>,
+    pub void_of_course: Vec<VoidOfCourse>,
+    pub ingresses: Vec<SignIngress>,
+}
+
+/// Angle between two bodies (first minus second, 0-360)
+fn elongation(jd: f64, body: CelestialBody, from: CelestialBody) -> Result<f64, String> {
+    let (lon, _) = calculate_planet_speed(jd, body, ZodiacMode::Sidereal)?;
+    let (from_lon, _) = calculate_planet_speed(jd, from, ZodiacMode::Sidereal)?;
+    Ok((lon - from_lon).rem_euclid(360.0))
+}
+
+/// Times in [start_jd, end_jd] when the Moon's elongation from `from` passes `angle`
+fn elongation_crossings(
+    from: CelestialBody,
+    angle: f64,
+    start_jd: f64,
+    end_jd: f64,
+) -> Result<Vec<f64>, String> {
+    let diff = |jd: f64| -> Result<f64, String> {
+        Ok(longitude_difference(
+            angle,
+            elongation(jd, CelestialBody::Moon, from)?,
+        ))
+    };
+
+    let mut crossings = Vec::new();
+    let mut jd = start_jd;
+    let mut before = diff(jd)?;
+    while jd < end_jd {
+        let next_jd = (jd + MOON_STEP).min(end_jd);
+        let after = diff(next_jd)?;
+        if (before < 0.0) != (after < 0.0) && (before - after).abs() < 180.0 {
+            let (mut low, mut high) = (jd, next_jd);
+            for _ in 0..50 {
+                let mid = (low + high) / 2.0;
+                if (diff(mid)? < 0.0) == (before < 0.0) {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            crossings.push((low + high) / 2.0);
+        }
+        jd = next_jd;
+        before = after;
+    }
+    Ok(crossings)
+}
+
+/// Phase angle, named phase and illumination of the Moon at `datetime`
+pub fn calculate_moon_phase(datetime: DateTime<Utc>) -> Result<MoonPhaseInfo, String> {
+    let angle = elongation(
+        calculate_jd(&datetime),
+        CelestialBody::Moon,
+        CelestialBody::Sun,
+    )?;
+    Ok(MoonPhaseInfo {
+        angle,
+        phase: MoonPhase::from_angle(angle),
+        illumination: (1.0 - angle.to_radians().cos()) / 2.0,
+    })
+}
+
+/// Lunations, void-of-course periods and Moon sign ingresses between `start` and `end`
+pub fn calculate_moon_calendar(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    options: &MoonOptions,
+) -> Result<MoonCalendar, String> {
+    let start_jd = calculate_jd(&start);
+    let end_jd = calculate_jd(&end);
+    if end_jd <= start_jd {
+        return Err("Calendar end must be after start".to_string());
+    }
+
+    let mut calendar = MoonCalendar::default();
+
+    for kind in [
+        LunationKind::NewMoon,
+        LunationKind::FirstQuarter,
+        LunationKind::FullMoon,
+        LunationKind::LastQuarter,
+    ] {
+        for jd in elongation_crossings(CelestialBody::Sun, kind.angle(), start_jd, end_jd)? {
+            calendar.lunations.push(Lunation {
+                kind,
+                datetime: jd_to_datetime(jd),
+                longitude: calculate_planet_speed(jd, CelestialBody::Moon, options.mode)?.0,
+            });
+        }
+    }
+    calendar.lunations.sort_by_key(|l| l.datetime);
+
+    // Ingresses a few days either side so periods overlapping the range are complete
+    let margin = Duration::days(3);
+    let ingresses = calculate_station_calendar(
+        start - margin,
+        end + margin,
+        &StationCalendarOptions::new()
+            .with_bodies(vec![CelestialBody::Moon])
+            .with_mode(options.mode),
+    )?
+    .sign_ingresses;
+
+    for pair in ingresses.windows(2) {
+        let (entered, left) = (&pair[0], &pair[1]);
+        let sign_start = calculate_jd(&entered.datetime);
+        let sign_end = calculate_jd(&left.datetime);
+        if sign_end < start_jd {
+            continue;
+        }
+
+        // Last exact aspect while the Moon is in this sign
+        let mut last_aspect: Option<(f64, AspectType, CelestialBody)> = None;
+        for body in options.bodies.iter().copied() {
+            for aspect_type in options.aspects.iter().copied() {
+                let angle = aspect_type.angle();
+                let mut angles = vec![angle];
+                if angle > 0.0 && angle < 180.0 {
+                    angles.push(360.0 - angle);
+                }
+                for angle in angles {
+                    for jd in elongation_crossings(body, angle, sign_start, sign_end)? {
+                        if last_aspect.is_none_or(|(last, _, _)| jd > last) {
+                            last_aspect = Some((jd, aspect_type, body));
+                        }
+                    }
+                }
+            }
+        }
+
+        let void_start = last_aspect.map_or(sign_start, |(jd, _, _)| jd);
+        if void_start > end_jd {
+            continue;
+        }
+        calendar.void_of_course.push(VoidOfCourse {
+            start: jd_to_datetime(void_start),
+            end: left.datetime,
+            sign: entered.sign,
+            last_aspect: last_aspect.map(|(_, aspect_type, body)| (aspect_type, body)),
+        });
+    }
+
+    calendar.ingresses = ingresses
+        .into_iter()
+        .filter(|i| i.datetime >= start && i.datetime <= end)
+        .collect();
+
+    Ok(calendar)
+}
+
+impl MoonCalendar {
+    /// Calendar events: lunations and ingresses are instants, void periods have a duration
+    pub fn events(&self) -> Vec<CalendarEvent> {
+        let mut events = Vec::new();
+
+        for lunation in &self.lunations {
+            events.push(CalendarEvent {
+                start: lunation.datetime,
+                end: None,
+                summary: format!(
+                    "{} {} {}",
+                    lunation.kind.to_string(),
+                    (lunation.longitude % 30.0).floor() as u32,
+                    ZodiacSign::from_longitude(lunation.longitude).to_string()
+                ),
+                description: String::new(),
+            });
+        }
+        for void in &self.void_of_course {
+            let description = match void.last_aspect {
+                Some((aspect_type, body)) => {
+                    format!(
+                        "Last aspect: Moon {} {}",
+                        aspect_type.symbol(),
+                        body
+                    )
+                }
+                None => "No aspects in this sign".to_string(),
+            };
+            events.push(CalendarEvent {
+                start: void.start,
+                end: Some(void.end),
+                summary: format!("Moon void of course in {}", void.sign.to_string()),
+                description,
+            });
+        }
+        for ingress in &self.ingresses {
+            events.push(CalendarEvent {
+                start: ingress.datetime,
+                end: None,
+                summary: format!("Moon enters {}", ingress.sign.to_string()),
+                description: String::new(),
+            });
+        }
+
+        events.sort_by_key(|e| e.start);
+        events
+    }
+
+    pub fn to_ics(&self) -> String {
+        events_to_ics(&self.events(), "Moon")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sweph::init_sweph;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_lunations_and_void_of_course() {
+        init_sweph();
+        let start = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap();
+        let calendar = calculate_moon_calendar(start, end, &MoonOptions::new()).unwrap();
+
+        // Total solar eclipse New Moon: 2024-04-08 18:21 UT
+        let new_moon = calendar
+            .lunations
+            .iter()
+            .find(|l| l.kind == LunationKind::NewMoon)
+            .unwrap();
+        assert_eq!(
+            new_moon.datetime.format("%Y-%m-%d %H").to_string(),
+            "2024-04-08 18"
+        );
+        assert_eq!(
+            calculate_moon_phase(new_moon.datetime).unwrap().phase,
+            MoonPhase::NewMoon
+        );
+
+        // The Moon changes sign every ~2.5 days, each ingress ends a void period
+        assert!(calendar.ingresses.len() >= 12);
+        for void in &calendar.void_of_course {
+            assert!(void.start <= void.end);
+            assert!(void.end - void.start < Duration::days(3));
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use crate::calendar::{csv_field, events_to_ics, CalendarEvent};
+use crate::chart::CelestialBody;
+use crate::rise_set::{local_midnight_jd, next_rise_trans};
+use crate::sweph::{east_longitude, jd_to_datetime, HorizonOptions, SE_CALC_RISE, SE_CALC_SET};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+
+/// Chaldean order, slowest to fastest; each hour passes to the next planet
+pub const CHALDEAN_ORDER: [CelestialBody; 7] = [
+    CelestialBody::Saturn,
+    CelestialBody::Jupiter,
+    CelestialBody::Mars,
+    CelestialBody::Sun,
+    CelestialBody::Venus,
+    CelestialBody::Mercury,
+    CelestialBody::Moon,
+];
+
+/// Ruler of the planetary day (and of its first hour)
+pub fn day_ruler(weekday: Weekday) -> CelestialBody {
+    match weekday {
+        Weekday::Sun => CelestialBody::Sun,
+        Weekday::Mon => CelestialBody::Moon,
+        Weekday::Tue => CelestialBody::Mars,
+        Weekday::Wed => CelestialBody::Mercury,
+        Weekday::Thu => CelestialBody::Jupiter,
+        Weekday::Fri => CelestialBody::Venus,
+        Weekday::Sat => CelestialBody::Saturn,
+    }
+}
+
+/// One of the 24 unequal hours of a planetary day
+#[derive(Debug, Clone)]
+pub struct PlanetaryHour {
+    pub number: u32, // 1-12 day hours, 13-24 night hours
+    pub is_day: bool,
+    pub ruler: CelestialBody,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl PlanetaryHour {
+    /// "Day hour 3" / "Night hour 3"
+    pub fn label(&self) -> String {
+        if self.is_day {
+            format!("Day hour {}", self.number)
+        } else {
+            format!("Night hour {}", self.number - 12)
+        }
+    }
+}
+
+/// Sunrise to the next sunrise, ruled by the planet of the weekday
+#[derive(Debug, Clone)]
+pub struct PlanetaryDay {
+    pub date: NaiveDate,
+    pub day_ruler: CelestialBody,
+    pub sunrise: DateTime<Utc>,
+    pub sunset: DateTime<Utc>,
+    pub next_sunrise: DateTime<Utc>,
+    pub hours: Vec<PlanetaryHour>,
+}
+
+/// Planetary day starting at sunrise on the local date `date`
+/// Fails where the Sun does not rise or set (polar day or night)
+pub fn calculate_planetary_day(
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+    options: &HorizonOptions,
+) -> Result<PlanetaryDay, String> {
+    let sun = CelestialBody::Sun;
+    let no_sunrise = || format!("The Sun does not rise and set on {}", date);
+
+    let start = local_midnight_jd(date, longitude);
+    let sunrise = next_rise_trans(start, sun, SE_CALC_RISE, latitude, longitude, options)?
+        .filter(|jd| *jd < start + 1.0)
+        .ok_or_else(no_sunrise)?;
+    let sunset = next_rise_trans(sunrise, sun, SE_CALC_SET, latitude, longitude, options)?
+        .ok_or_else(no_sunrise)?;
+    let next_sunrise = next_rise_trans(sunset, sun, SE_CALC_RISE, latitude, longitude, options)?
+        .ok_or_else(no_sunrise)?;
+
+    let ruler = day_ruler(date.weekday());
+    let first = CHALDEAN_ORDER.iter().position(|b| *b == ruler).unwrap();
+    let day_hour = (sunset - sunrise) / 12.0;
+    let night_hour = (next_sunrise - sunset) / 12.0;
+
+    let hours = (0..24)
+        .map(|i| {
+            let is_day = i < 12;
+            let (begin, length, index) = if is_day {
+                (sunrise, day_hour, i)
+            } else {
+                (sunset, night_hour, i - 12)
+            };
+            PlanetaryHour {
+                number: i as u32 + 1,
+                is_day,
+                ruler: CHALDEAN_ORDER[(first + i) % 7],
+                start: jd_to_datetime(begin + length * index as f64),
+                end: jd_to_datetime(begin + length * (index + 1) as f64),
+            }
+        })
+        .collect();
+
+    Ok(PlanetaryDay {
+        date,
+        day_ruler: ruler,
+        sunrise: jd_to_datetime(sunrise),
+        sunset: jd_to_datetime(sunset),
+        next_sunrise: jd_to_datetime(next_sunrise),
+        hours,
+    })
+}
+
+/// Planetary days for every local date from `start` to `end` inclusive
+pub fn calculate_planetary_hours(
+    start: NaiveDate,
+    end: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+    options: &HorizonOptions,
+) -> Result<Vec<PlanetaryDay>, String> {
+    if end < start {
+        return Err("End date must not be before start date".to_string());
+    }
+    start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .map(|d| calculate_planetary_day(d, latitude, longitude, options))
+        .collect()
+}
+
+/// Planetary hour in effect at `datetime`
+/// Before sunrise the hour belongs to the previous day's night
+pub fn planetary_hour_at(
+    datetime: DateTime<Utc>,
+    latitude: f64,
+    longitude: f64,
+    options: &HorizonOptions,
+) -> Result<PlanetaryHour, String> {
+    // Local mean date at the location
+    let local = datetime + Duration::seconds((east_longitude(longitude) * 240.0) as i64);
+
+    let mut day = calculate_planetary_day(local.date_naive(), latitude, longitude, options)?;
+    if datetime < day.sunrise {
+        day = calculate_planetary_day(
+            local.date_naive().pred_opt().unwrap(),
+            latitude,
+            longitude,
+            options,
+        )?;
+    }
+
+    day.hours
+        .into_iter()
+        .find(|h| h.start <= datetime && datetime < h.end)
+        .ok_or(format!("No planetary hour found at {}", datetime))
+}
+
+/// CSV with one row per planetary hour
+pub fn planetary_hours_csv(days: &[PlanetaryDay]) -> String {
+    let mut rows = vec!["date,day_ruler,hour,period,ruler,start,end".to_string()];
+    for day in days {
+        for hour in &day.hours {
+            rows.push(
+                [
+                    day.date.to_string(),
+                    csv_field(&day.day_ruler.to_string()),
+                    hour.number.to_string(),
+                    if hour.is_day { "day" } else { "night" }.to_string(),
+                    csv_field(&hour.ruler.to_string()),
+                    hour.start.to_rfc3339(),
+                    hour.end.to_rfc3339(),
+                ]
+                .join(","),
+            );
+        }
+    }
+    rows.join("\n")
+}
+
+/// iCalendar with one event per planetary hour
+pub fn planetary_hours_ics(days: &[PlanetaryDay]) -> String {
+    let entries: Vec<CalendarEvent> = days
+        .iter()
+        .flat_map(|day| {
+            day.hours.iter().map(move |hour| CalendarEvent {
+                start: hour.start,
+                end: Some(hour.end),
+                summary: format!("{} hour", hour.ruler),
+                description: format!(
+                    "{} of the {} day ({})",
+                    hour.label(),
+                    day.day_ruler,
+                    day.date
+                ),
+            })
+        })
+        .collect();
+    events_to_ics(&entries, "Planetary Hours")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sweph::init_sweph;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_friday_planetary_hours() {
+        init_sweph();
+        let date = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(); // Friday
+        let options = HorizonOptions::default();
+        let day = calculate_planetary_day(date, 51.5074, -0.1278, &options).unwrap();
+
+        assert_eq!(day.day_ruler, CelestialBody::Venus);
+        assert_eq!(day.hours.len(), 24);
+        assert_eq!(day.hours[0].ruler, CelestialBody::Venus);
+        assert_eq!(day.hours[1].ruler, CelestialBody::Mercury);
+        assert_eq!(day.hours[7].ruler, CelestialBody::Venus);
+        // First hour of Friday night belongs to Mars
+        assert_eq!(day.hours[12].ruler, CelestialBody::Mars);
+        assert_eq!(day.hours[0].start, day.sunrise);
+        assert_eq!(day.hours[12].start, day.sunset);
+        // Midsummer: day hours are longer than night hours
+        let day_hour = day.hours[0].end - day.hours[0].start;
+        let night_hour = day.hours[12].end - day.hours[12].start;
+        assert!(day_hour > night_hour);
+
+        // Early Saturday morning is still Friday's night
+        let before_dawn = Utc.with_ymd_and_hms(2024, 6, 22, 2, 0, 0).unwrap();
+        let hour = planetary_hour_at(before_dawn, 51.5074, -0.1278, &options).unwrap();
+        assert!(!hour.is_day);
+        assert_eq!(hour.number, 22);
+    }
+}
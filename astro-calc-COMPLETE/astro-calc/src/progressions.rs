@@ -0,0 +1,167 @@
+use crate::aspects::{calculate_cross_aspects, Aspect};
+use crate::chart::{AnglePoint, AnglePosition, CelestialBody, Chart, Position};
+use crate::sweph::{
+    calculate_armc, calculate_chart, calculate_houses_from_armc, calculate_jd, calculate_planet,
+    east_longitude, mc_to_armc, ChartInput,
+};
+use chrono::{DateTime, Utc};
+
+/// Length of the tropical year in days (one ephemeris day = one year of life)
+pub const TROPICAL_YEAR_DAYS: f64 = 365.242199;
+
+/// Naibod rate: the Sun's mean daily motion (59'08.33") applied per year
+pub const NAIBOD_RATE: f64 = 0.985_647_4;
+
+/// Mean advance of the ARMC per day (one sidereal day)
+const SIDEREAL_DAY_RATE: f64 = 360.985_647_366_29;
+
+/// Orb for progressed-to-natal aspects
+pub const PROGRESSED_ASPECT_ORB: f64 = 1.0;
+
+/// How the progressed MC (and from it the other angles and cusps) is found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressedAngleMethod {
+    Naibod,        // Natal ARMC + Naibod rate per year
+    SolarArc,      // Natal MC + the progressed Sun's arc in longitude
+    MeanQuotidian, // ARMC advanced by a full sidereal day per progressed day
+}
+
+impl ProgressedAngleMethod {
+    pub fn to_string(&self) -> &str {
+        match self {
+            ProgressedAngleMethod::Naibod => "Naibod",
+            ProgressedAngleMethod::SolarArc => "Solar Arc",
+            ProgressedAngleMethod::MeanQuotidian => "Mean Quotidian",
+        }
+    }
+}
+
+/// Julian Day of the progressed (day-for-a-year) ephemeris date
+pub fn progressed_jd(natal_jd: f64, target_jd: f64) -> f64 {
+    natal_jd + (target_jd - natal_jd) / TROPICAL_YEAR_DAYS
+}
+
+/// Calculate a secondary progressed chart for `target`
+/// Returns (natal_chart, progressed_chart, progressed-to-natal aspects)
+/// Like transits, the progressed chart has no Fortuna, lots or Vertex
+pub fn calculate_progressed_chart(
+    natal: &ChartInput,
+    target: DateTime<Utc>,
+    method: ProgressedAngleMethod,
+) -> Result<(Chart, Chart, Vec<Aspect>), String> {
+    if target < natal.datetime {
+        return Err("Progression date is before birth".to_string());
+    }
+
+    let natal_chart = calculate_chart(natal)?;
+
+    let natal_jd = calculate_jd(&natal.datetime);
+    let prog_jd = progressed_jd(natal_jd, calculate_jd(&target));
+    let age_years = prog_jd - natal_jd;
+    let lon_east = east_longitude(natal.longitude);
+
+    // Progressed angles
+    let natal_armc = calculate_armc(natal_jd, natal.latitude, lon_east)?;
+    let armc = match method {
+        ProgressedAngleMethod::Naibod => natal_armc + age_years * NAIBOD_RATE,
+        ProgressedAngleMethod::MeanQuotidian => natal_armc + age_years * SIDEREAL_DAY_RATE,
+        ProgressedAngleMethod::SolarArc => {
+            let natal_sun = calculate_planet(natal_jd, CelestialBody::Sun)?.0;
+            let progressed_sun = calculate_planet(prog_jd, CelestialBody::Sun)?.0;
+            let arc = (progressed_sun - natal_sun).rem_euclid(360.0);
+            let natal_mc = natal_chart
+                .get_angle(AnglePoint::Midheaven)
+                .map(|a| a.longitude)
+                .ok_or("Natal chart has no MC")?;
+            mc_to_armc(prog_jd, (natal_mc + arc) % 360.0)?
+        }
+    };
+    let (houses, asc, mc, _vertex) =
+        calculate_houses_from_armc(prog_jd, armc.rem_euclid(360.0), natal.latitude)?;
+
+    let mut progressed = Chart::new().with_metadata(natal.name.clone(), natal.gender.clone());
+    progressed.houses = houses;
+    progressed.angles.push(AnglePosition {
+        angle: AnglePoint::Ascendant,
+        longitude: asc,
+    });
+    progressed.angles.push(AnglePosition {
+        angle: AnglePoint::Midheaven,
+        longitude: mc,
+    });
+
+    // Progressed planets in progressed houses
+    for body in natal.bodies.iter().copied() {
+        if matches!(
+            body,
+            CelestialBody::Fortuna | CelestialBody::Vertex | CelestialBody::Lot(_)
+        ) {
+            continue;
+        }
+
+        let (longitude, retrograde) = calculate_planet(prog_jd, body)?;
+        progressed.positions.push(Position {
+            body,
+            longitude,
+            retrograde,
+            house: progressed.houses.get_house(longitude),
+            horizon: None,
+        });
+    }
+
+    let aspects = calculate_cross_aspects(&progressed, &natal_chart, PROGRESSED_ASPECT_ORB);
+
+    Ok((natal_chart, progressed, aspects))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sweph::init_sweph;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_progression_at_birth_matches_natal() {
+        init_sweph();
+        let birth = Utc.with_ymd_and_hms(1985, 3, 10, 8, 15, 0).unwrap();
+        let input = ChartInput::new(birth, 51.5074, -0.1278);
+
+        for method in [
+            ProgressedAngleMethod::Naibod,
+            ProgressedAngleMethod::SolarArc,
+            ProgressedAngleMethod::MeanQuotidian,
+        ] {
+            let (natal, progressed, _) =
+                calculate_progressed_chart(&input, birth, method).unwrap();
+            let natal_asc = natal.get_angle(AnglePoint::Ascendant).unwrap().longitude;
+            let progressed_asc = progressed
+                .get_angle(AnglePoint::Ascendant)
+                .unwrap()
+                .longitude;
+            assert!((natal_asc - progressed_asc).abs() < 0.05);
+            assert!(
+                (natal.get_position(CelestialBody::Sun).unwrap().longitude
+                    - progressed
+                        .get_position(CelestialBody::Sun)
+                        .unwrap()
+                        .longitude)
+                    .abs()
+                    < 1e-9
+            );
+        }
+
+        let before_birth = birth - chrono::Duration::days(1);
+        assert!(
+            calculate_progressed_chart(&input, before_birth, ProgressedAngleMethod::Naibod)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_progressed_jd() {
+        // Thirty years after birth is thirty days of ephemeris motion
+        let natal_jd = 2_446_000.0;
+        let target_jd = natal_jd + 30.0 * TROPICAL_YEAR_DAYS;
+        assert!((progressed_jd(natal_jd, target_jd) - (natal_jd + 30.0)).abs() < 1e-9);
+    }
+}
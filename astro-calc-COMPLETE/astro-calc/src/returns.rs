@@ -0,0 +1,427 @@
+use crate::chart::{CelestialBody, Chart};
+use crate::progressions::TROPICAL_YEAR_DAYS;
+use crate::sweph::{
+    calculate_chart, calculate_jd, calculate_planet_speed, jd_to_datetime, ChartInput, ZodiacMode,
+};
+use chrono::{DateTime, Utc};
+
+/// Longitude tolerance for an exact return (degrees, ~0.04" of arc)
+const RETURN_TOLERANCE: f64 = 1e-5;
+
+/// Maximum Newton iterations when refining a return instant
+const MAX_ITERATIONS: usize = 50;
+
+/// Which luminary's return to calculate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnKind {
+    Solar,
+    Lunar,
+}
+
+impl ReturnKind {
+    pub fn to_string(&self) -> &str {
+        match self {
+            ReturnKind::Solar => "Solar Return",
+            ReturnKind::Lunar => "Lunar Return",
+        }
+    }
+
+    pub fn body(&self) -> CelestialBody {
+        match self {
+            ReturnKind::Solar => CelestialBody::Sun,
+            ReturnKind::Lunar => CelestialBody::Moon,
+        }
+    }
+
+    /// Mean daily motion in degrees, used for the first guess
+    fn mean_motion(&self) -> f64 {
+        match self {
+            ReturnKind::Solar => 0.985_647,
+            ReturnKind::Lunar => 13.176_358,
+        }
+    }
+}
+
+/// Return chart settings
+/// Return charts are sidereal like every other chart, so the return is the
+/// sidereal (precession-corrected) one; use `find_return` for a tropical instant
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReturnOptions {
+    pub kind: ReturnKind,
+    pub location: Option<(f64, f64)>, // (latitude, longitude) when relocated; None = birth place
+}
+
+impl ReturnOptions {
+    pub fn new(kind: ReturnKind) -> Self {
+        Self {
+            kind,
+            location: None,
+        }
+    }
+
+    /// Cast the return chart for where the person is at the return
+    pub fn with_location(mut self, latitude: f64, longitude: f64) -> Self {
+        self.location = Some((latitude, longitude));
+        self
+    }
+}
+
+/// A calculated return
+#[derive(Debug, Clone)]
+pub struct ReturnChart {
+    pub kind: ReturnKind,
+    pub datetime: DateTime<Utc>,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub natal: Chart,
+    pub chart: Chart, // Cast for the return instant and location, with its own houses
+}
+
+/// Signed difference target - longitude in (-180, 180]
+pub(crate) fn longitude_difference(target: f64, longitude: f64) -> f64 {
+    let diff = (target - longitude).rem_euclid(360.0);
+    if diff > 180.0 {
+        diff - 360.0
+    } else {
+        diff
+    }
+}
+
+/// Refine the moment `body` reaches `target` longitude, starting near `guess_jd`
+/// Newton's method on the swe_calc_ut daily speed
+pub(crate) fn solve_longitude(
+    body: CelestialBody,
+    target: f64,
+    guess_jd: f64,
+    mode: ZodiacMode,
+) -> Result<f64, String> {
+    let mut jd = guess_jd;
+    for _ in 0..MAX_ITERATIONS {
+        let (longitude, speed) = calculate_planet_speed(jd, body, mode)?;
+        let diff = longitude_difference(target, longitude);
+        if diff.abs() < RETURN_TOLERANCE {
+            return Ok(jd);
+        }
+        if speed.abs() < 1e-6 {
+            return Err(format!(
+                "{} is stationary near the return",
+                body
+            ));
+        }
+        jd += diff / speed;
+    }
+    Err(format!("{} return did not converge", body))
+}
+
+/// Find the first Solar or Lunar return after `after`
+pub fn find_return(
+    natal: &ChartInput,
+    kind: ReturnKind,
+    after: DateTime<Utc>,
+    mode: ZodiacMode,
+) -> Result<DateTime<Utc>, String> {
+    let body = kind.body();
+    let after_jd = calculate_jd(&after);
+    let (target, _) = calculate_planet_speed(calculate_jd(&natal.datetime), body, mode)?;
+    let (start_lon, _) = calculate_planet_speed(after_jd, body, mode)?;
+
+    let mut guess = after_jd + (target - start_lon).rem_euclid(360.0) / kind.mean_motion();
+    let mut jd = solve_longitude(body, target, guess, mode)?;
+    if jd < after_jd {
+        // Converged onto the previous return; step one cycle forward
+        guess = jd + 360.0 / kind.mean_motion();
+        jd = solve_longitude(body, target, guess, mode)?;
+    }
+
+    Ok(jd_to_datetime(jd))
+}
+
+/// Calculate the first return after `after` and cast its chart
+pub fn calculate_return_chart(
+    natal: &ChartInput,
+    after: DateTime<Utc>,
+    options: &ReturnOptions,
+) -> Result<ReturnChart, String> {
+    let natal_chart = calculate_chart(natal)?;
+    let datetime = find_return(natal, options.kind, after, ZodiacMode::Sidereal)?;
+    let (latitude, longitude) = options
+        .location
+        .unwrap_or((natal.latitude, natal.longitude));
+
+    let mut input =
+        ChartInput::new(datetime, latitude, longitude).with_bodies(natal.bodies.clone());
+    input.name = natal.name.clone();
+    input.gender = natal.gender.clone();
+    let chart = calculate_chart(&input)?;
+
+    Ok(ReturnChart {
+        kind: options.kind,
+        datetime,
+        latitude,
+        longitude,
+        natal: natal_chart,
+        chart,
+    })
+}
+
+/// A return (or fractional return) of any body, one pass of a possible retrograde loop
+#[derive(Debug, Clone)]
+pub struct PlanetaryReturn {
+    pub body: CelestialBody,
+    pub harmonic: u32, // Cycle divided into this many parts (1 = return, 2 = half-returns, ...)
+    pub fraction: u32, // Part reached: target = natal + fraction * 360 / harmonic
+    pub target_longitude: f64,
+    pub datetime: DateTime<Utc>,
+    pub age: f64,         // Years since birth
+    pub pass: usize,      // 1-based pass within a retrograde loop
+    pub passes: usize,    // Passes in this loop (1-3; fewer at the edges of the age range)
+    pub retrograde: bool, // Moving against its usual direction at this pass
+    pub chart: Chart,     // Cast for the birth place
+}
+
+impl PlanetaryReturn {
+    /// "Saturn Return", "Saturn Half-Return", "Mars Waxing Quarter-Return", "Jupiter 3/8 Return"
+    pub fn label(&self) -> String {
+        let body = self.body.to_string();
+        let (fraction, harmonic) = reduce_fraction(self.fraction, self.harmonic);
+        match (fraction, harmonic) {
+            (0, _) => format!("{} Return", body),
+            (1, 2) => format!("{} Half-Return", body),
+            (1, 4) => format!("{} Waxing Quarter-Return", body),
+            (3, 4) => format!("{} Waning Quarter-Return", body),
+            (f, h) => format!("{} {}/{} Return", body, f, h),
+        }
+    }
+}
+
+fn reduce_fraction(fraction: u32, harmonic: u32) -> (u32, u32) {
+    let (mut a, mut b) = (fraction, harmonic);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    if fraction == 0 || a == 0 {
+        (0, harmonic)
+    } else {
+        (fraction / a, harmonic / a)
+    }
+}
+
+/// Bodies whose usual motion is retrograde
+fn normally_retrograde(body: CelestialBody) -> bool {
+    matches!(
+        body,
+        CelestialBody::TrueNode | CelestialBody::MeanNode | CelestialBody::SouthNode
+    )
+}
+
+/// Sampling step (days) small enough that no two crossings of a target fall in one step
+pub(crate) fn scan_step(body: CelestialBody) -> f64 {
+    match body {
+        CelestialBody::Moon | CelestialBody::TrueLilith => 0.25,
+        _ => 1.0,
+    }
+}
+
+/// Bisect for the crossing of `target` bracketed by [low, high]
+pub(crate) fn bisect_longitude(
+    body: CelestialBody,
+    target: f64,
+    mut low: f64,
+    mut high: f64,
+    mode: ZodiacMode,
+) -> Result<f64, String> {
+    let low_sign = longitude_difference(target, calculate_planet_speed(low, body, mode)?.0) < 0.0;
+    for _ in 0..MAX_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        let (longitude, _) = calculate_planet_speed(mid, body, mode)?;
+        if (longitude_difference(target, longitude) < 0.0) == low_sign {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Ok((low + high) / 2.0)
+}
+
+/// Find every return of `body` to its natal longitude plus each 1/harmonic of the cycle,
+/// between two ages (in years). Retrograde loops report every pass.
+pub fn calculate_planetary_returns(
+    natal: &ChartInput,
+    body: CelestialBody,
+    harmonic: u32,
+    start_age: f64,
+    end_age: f64,
+    mode: ZodiacMode,
+) -> Result<Vec<PlanetaryReturn>, String> {
+    if harmonic == 0 {
+        return Err("Harmonic must be at least 1".to_string());
+    }
+    if end_age <= start_age {
+        return Err("End age must be after start age".to_string());
+    }
+
+    let natal_jd = calculate_jd(&natal.datetime);
+    let (natal_lon, _) = calculate_planet_speed(natal_jd, body, mode)?;
+    let targets: Vec<f64> = (0..harmonic)
+        .map(|k| (natal_lon + k as f64 * 360.0 / harmonic as f64).rem_euclid(360.0))
+        .collect();
+
+    let start_jd = natal_jd + start_age * TROPICAL_YEAR_DAYS;
+    let end_jd = natal_jd + end_age * TROPICAL_YEAR_DAYS;
+    let step = scan_step(body);
+
+    // Crossings per target: (jd, moving against the usual direction)
+    let mut crossings: Vec<Vec<(f64, bool)>> = vec![Vec::new(); targets.len()];
+    let mut previous_jd = start_jd;
+    let mut previous_lon = calculate_planet_speed(start_jd, body, mode)?.0;
+
+    while previous_jd < end_jd {
+        let jd = (previous_jd + step).min(end_jd);
+        let (longitude, _) = calculate_planet_speed(jd, body, mode)?;
+
+        for (i, target) in targets.iter().enumerate() {
+            let before = longitude_difference(*target, previous_lon);
+            let after = longitude_difference(*target, longitude);
+            // A sign change near the target (not the jump on the far side of the circle)
+            if (before < 0.0) != (after < 0.0) && (before - after).abs() < 180.0 {
+                let root = bisect_longitude(body, *target, previous_jd, jd, mode)?;
+                let (_, speed) = calculate_planet_speed(root, body, mode)?;
+                let against = (speed < 0.0) != normally_retrograde(body);
+                crossings[i].push((root, against));
+            }
+        }
+
+        previous_jd = jd;
+        previous_lon = longitude;
+    }
+
+    let mut returns = Vec::new();
+    for (fraction, target) in targets.iter().enumerate() {
+        // A retrograde pass ties the passes before and after it into one loop
+        let mut groups: Vec<Vec<(f64, bool)>> = Vec::new();
+        for crossing in &crossings[fraction] {
+            match groups.last_mut() {
+                Some(group) if crossing.1 || group.last().is_some_and(|c| c.1) => {
+                    group.push(*crossing)
+                }
+                _ => groups.push(vec![*crossing]),
+            }
+        }
+
+        for group in groups {
+            let passes = group.len();
+            for (pass, (jd, against)) in group.into_iter().enumerate() {
+                let datetime = jd_to_datetime(jd);
+                let mut input = ChartInput::new(datetime, natal.latitude, natal.longitude)
+                    .with_bodies(natal.bodies.clone());
+                input.name = natal.name.clone();
+                input.gender = natal.gender.clone();
+
+                returns.push(PlanetaryReturn {
+                    body,
+                    harmonic,
+                    fraction: fraction as u32,
+                    target_longitude: *target,
+                    datetime,
+                    age: (jd - natal_jd) / TROPICAL_YEAR_DAYS,
+                    pass: pass + 1,
+                    passes,
+                    retrograde: against,
+                    chart: calculate_chart(&input)?,
+                });
+            }
+        }
+    }
+
+    returns.sort_by_key(|r| r.datetime);
+    Ok(returns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sweph::init_sweph;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_solar_and_lunar_return_longitudes() {
+        init_sweph();
+        let birth = Utc.with_ymd_and_hms(1980, 6, 15, 14, 30, 0).unwrap();
+        let input = ChartInput::new(birth, 40.7128, -74.0060);
+        let after = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        for (kind, period_days) in [(ReturnKind::Solar, 366.0), (ReturnKind::Lunar, 28.0)] {
+            for mode in [ZodiacMode::Sidereal, ZodiacMode::Tropical] {
+                let datetime = find_return(&input, kind, after, mode).unwrap();
+                let jd = calculate_jd(&datetime);
+                assert!(jd > calculate_jd(&after));
+                assert!(jd - calculate_jd(&after) < period_days);
+
+                let (natal_lon, _) =
+                    calculate_planet_speed(calculate_jd(&birth), kind.body(), mode).unwrap();
+                let (return_lon, _) = calculate_planet_speed(jd, kind.body(), mode).unwrap();
+                // Rounded to the second: the Moon moves ~0.00015° per second
+                assert!(longitude_difference(natal_lon, return_lon).abs() < 0.001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sidereal_return_is_later_than_tropical() {
+        init_sweph();
+        let birth = Utc.with_ymd_and_hms(1950, 3, 1, 6, 0, 0).unwrap();
+        let input = ChartInput::new(birth, 51.5074, -0.1278);
+        let after = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+
+        // Seventy years of precession (~1°) delays the sidereal Solar Return by about a day
+        let sidereal = find_return(&input, ReturnKind::Solar, after, ZodiacMode::Sidereal).unwrap();
+        let tropical = find_return(&input, ReturnKind::Solar, after, ZodiacMode::Tropical).unwrap();
+        let delay = calculate_jd(&sidereal) - calculate_jd(&tropical);
+        assert!(delay > 0.8 && delay < 1.2);
+
+        // The return chart's Sun sits on the natal Sun in the chart's own frame
+        let ret = calculate_return_chart(&input, after, &ReturnOptions::new(ReturnKind::Solar))
+            .unwrap();
+        assert_eq!(ret.datetime, sidereal);
+        let natal_sun = ret.natal.get_position(CelestialBody::Sun).unwrap().longitude;
+        let return_sun = ret.chart.get_position(CelestialBody::Sun).unwrap().longitude;
+        assert!(longitude_difference(natal_sun, return_sun).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_jupiter_return_and_half_return() {
+        init_sweph();
+        let birth = Utc.with_ymd_and_hms(1990, 9, 1, 12, 0, 0).unwrap();
+        let input =
+            ChartInput::new(birth, 48.8566, 2.3522).with_bodies(vec![CelestialBody::Jupiter]);
+        let returns = calculate_planetary_returns(
+            &input,
+            CelestialBody::Jupiter,
+            2,
+            1.0,
+            13.0,
+            ZodiacMode::Sidereal,
+        )
+        .unwrap();
+
+        // Jupiter's cycle is ~11.86 years: one half-return loop and one return loop
+        let full: Vec<_> = returns.iter().filter(|r| r.fraction == 0).collect();
+        let half: Vec<_> = returns.iter().filter(|r| r.fraction == 1).collect();
+        assert!(!full.is_empty() && !half.is_empty());
+        assert!(full.iter().all(|r| r.age > 11.0 && r.age < 13.0));
+        assert!(half.iter().all(|r| r.age > 5.0 && r.age < 7.5));
+        assert_eq!(half[0].label(), "Jupiter Half-Return");
+
+        for r in &returns {
+            assert!(r.passes == 1 || r.passes == 3);
+            // The middle pass of a triple is the retrograde one
+            assert_eq!(r.retrograde, r.passes == 3 && r.pass == 2);
+            let lon = r
+                .chart
+                .get_position(CelestialBody::Jupiter)
+                .unwrap()
+                .longitude;
+            assert!(longitude_difference(r.target_longitude, lon).abs() < 0.001);
+        }
+    }
+}
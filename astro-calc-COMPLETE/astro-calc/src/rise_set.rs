@@ -0,0 +1,349 @@
+use crate::chart::CelestialBody;
+use crate::sweph::{
+    calculate_jd, east_longitude, get_planet_id, jd_to_datetime, swe_rise_trans, ChartInput,
+    HorizonOptions, SE_BIT_ASTRO_TWILIGHT, SE_BIT_CIVIL_TWILIGHT, SE_BIT_NAUTIC_TWILIGHT,
+    SE_CALC_ITRANSIT, SE_CALC_MTRANSIT, SE_CALC_RISE, SE_CALC_SET,
+};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+
+/// swe_rise_trans return value when the body does not rise or set
+const CIRCUMPOLAR: c_int = -2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiseSetEvent {
+    Rise,
+    Set,
+    UpperCulmination, // Crossing the meridian above the horizon
+    LowerCulmination, // Crossing the meridian below the horizon
+}
+
+impl RiseSetEvent {
+    pub fn to_string(&self) -> &str {
+        match self {
+            RiseSetEvent::Rise => "Rise",
+            RiseSetEvent::Set => "Set",
+            RiseSetEvent::UpperCulmination => "Upper Culmination",
+            RiseSetEvent::LowerCulmination => "Lower Culmination",
+        }
+    }
+
+    fn flag(&self) -> c_int {
+        match self {
+            RiseSetEvent::Rise => SE_CALC_RISE,
+            RiseSetEvent::Set => SE_CALC_SET,
+            RiseSetEvent::UpperCulmination => SE_CALC_MTRANSIT,
+            RiseSetEvent::LowerCulmination => SE_CALC_ITRANSIT,
+        }
+    }
+}
+
+/// Sun depression for the start of dawn / end of dusk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Twilight {
+    Civil,        // Sun 6° below the horizon
+    Nautical,     // Sun 12° below the horizon
+    Astronomical, // Sun 18° below the horizon
+}
+
+impl Twilight {
+    pub fn to_string(&self) -> &str {
+        match self {
+            Twilight::Civil => "Civil",
+            Twilight::Nautical => "Nautical",
+            Twilight::Astronomical => "Astronomical",
+        }
+    }
+
+    pub fn all() -> Vec<Twilight> {
+        vec![Twilight::Civil, Twilight::Nautical, Twilight::Astronomical]
+    }
+
+    fn flag(&self) -> c_int {
+        match self {
+            Twilight::Civil => SE_BIT_CIVIL_TWILIGHT,
+            Twilight::Nautical => SE_BIT_NAUTIC_TWILIGHT,
+            Twilight::Astronomical => SE_BIT_ASTRO_TWILIGHT,
+        }
+    }
+}
+
+/// Horizon events of one body during one local day
+#[derive(Debug, Clone)]
+pub struct RiseSetTimes {
+    pub body: CelestialBody,
+    pub rise: Option<DateTime<Utc>>, // None = no rise during this day
+    pub set: Option<DateTime<Utc>>,
+    pub upper_culmination: Option<DateTime<Utc>>,
+    pub lower_culmination: Option<DateTime<Utc>>,
+    pub circumpolar: bool, // Never crosses the horizon (midnight sun, polar night)
+}
+
+/// Start of dawn and end of dusk for one twilight kind
+#[derive(Debug, Clone)]
+pub struct TwilightTimes {
+    pub twilight: Twilight,
+    pub dawn: Option<DateTime<Utc>>, // None = the Sun never gets this low (or high)
+    pub dusk: Option<DateTime<Utc>>,
+}
+
+/// Rise, set and culmination of each body plus twilight for one day and place
+#[derive(Debug, Clone)]
+pub struct DailyAlmanac {
+    pub date: NaiveDate,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub bodies: Vec<RiseSetTimes>,
+    pub twilight: Vec<TwilightTimes>,
+}
+
+impl DailyAlmanac {
+    /// Time from sunrise to sunset, when the Sun both rises and sets
+    pub fn day_length(&self) -> Option<Duration> {
+        let sun = self.bodies.iter().find(|b| b.body == CelestialBody::Sun)?;
+        match (sun.rise, sun.set) {
+            (Some(rise), Some(set)) if set > rise => Some(set - rise),
+            _ => None,
+        }
+    }
+}
+
+/// Next Julian Day after `jd` of a rise/set/transit search (`rsmi` flags)
+/// Returns None when the body stays above or below the horizon
+pub(crate) fn next_rise_trans(
+    jd: f64,
+    body: CelestialBody,
+    rsmi: c_int,
+    latitude: f64,
+    longitude: f64,
+    options: &HorizonOptions,
+) -> Result<Option<f64>, String> {
+    let planet_id = get_planet_id(body).ok_or(format!(
+        "Cannot calculate rise and set for {}",
+        body
+    ))?;
+
+    let mut geopos = [east_longitude(longitude), latitude, options.elevation];
+    let mut tret = [0.0; 10];
+    let mut serr = [0 as c_char; 256];
+    let result = unsafe {
+        swe_rise_trans(
+            jd,
+            planet_id,
+            std::ptr::null_mut(),
+            0,
+            rsmi,
+            geopos.as_mut_ptr(),
+            options.pressure,
+            options.temperature,
+            tret.as_mut_ptr(),
+            serr.as_mut_ptr(),
+        )
+    };
+
+    match result {
+        CIRCUMPOLAR => Ok(None),
+        r if r < 0 => {
+            let message = unsafe { CStr::from_ptr(serr.as_ptr()) };
+            Err(format!(
+                "Rise/set calculation failed for {}: {}",
+                body,
+                message.to_string_lossy()
+            ))
+        }
+        _ => Ok(Some(tret[0])),
+    }
+}
+
+/// Next rise, set or culmination of a body after `after`
+pub fn find_next_rise_set(
+    after: DateTime<Utc>,
+    body: CelestialBody,
+    event: RiseSetEvent,
+    latitude: f64,
+    longitude: f64,
+    options: &HorizonOptions,
+) -> Result<Option<DateTime<Utc>>, String> {
+    let jd = calculate_jd(&after);
+    Ok(next_rise_trans(jd, body, event.flag(), latitude, longitude, options)?.map(jd_to_datetime))
+}
+
+/// Julian Day of local mean midnight starting `date` at `longitude`
+pub(crate) fn local_midnight_jd(date: NaiveDate, longitude: f64) -> f64 {
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap().and_utc();
+    calculate_jd(&midnight) - east_longitude(longitude) / 360.0
+}
+
+/// First event in the local day [start, start + 1)
+fn event_in_day(
+    start: f64,
+    body: CelestialBody,
+    rsmi: c_int,
+    latitude: f64,
+    longitude: f64,
+    options: &HorizonOptions,
+) -> Result<Option<DateTime<Utc>>, String> {
+    Ok(
+        next_rise_trans(start, body, rsmi, latitude, longitude, options)?
+            .filter(|jd| *jd < start + 1.0)
+            .map(jd_to_datetime),
+    )
+}
+
+/// Rise, set and culminations of a body during the local day `date`
+/// The day runs from local mean midnight to midnight at the given longitude
+pub fn calculate_rise_set(
+    body: CelestialBody,
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+    options: &HorizonOptions,
+) -> Result<RiseSetTimes, String> {
+    let start = local_midnight_jd(date, longitude);
+    let rise_jd = next_rise_trans(start, body, SE_CALC_RISE, latitude, longitude, options)?;
+    let set_jd = next_rise_trans(start, body, SE_CALC_SET, latitude, longitude, options)?;
+    let in_day = |jd: Option<f64>| jd.filter(|jd| *jd < start + 1.0).map(jd_to_datetime);
+
+    Ok(RiseSetTimes {
+        body,
+        rise: in_day(rise_jd),
+        set: in_day(set_jd),
+        upper_culmination: event_in_day(
+            start,
+            body,
+            SE_CALC_MTRANSIT,
+            latitude,
+            longitude,
+            options,
+        )?,
+        lower_culmination: event_in_day(
+            start,
+            body,
+            SE_CALC_ITRANSIT,
+            latitude,
+            longitude,
+            options,
+        )?,
+        circumpolar: rise_jd.is_none() && set_jd.is_none(),
+    })
+}
+
+/// Dawn and dusk for one twilight kind during the local day `date`
+pub fn calculate_twilight(
+    twilight: Twilight,
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+    options: &HorizonOptions,
+) -> Result<TwilightTimes, String> {
+    let start = local_midnight_jd(date, longitude);
+    let sun = CelestialBody::Sun;
+    Ok(TwilightTimes {
+        twilight,
+        dawn: event_in_day(
+            start,
+            sun,
+            SE_CALC_RISE | twilight.flag(),
+            latitude,
+            longitude,
+            options,
+        )?,
+        dusk: event_in_day(
+            start,
+            sun,
+            SE_CALC_SET | twilight.flag(),
+            latitude,
+            longitude,
+            options,
+        )?,
+    })
+}
+
+/// Rise/set/culmination for each body plus civil, nautical and astronomical twilight
+pub fn calculate_daily_almanac(
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+    bodies: &[CelestialBody],
+    options: &HorizonOptions,
+) -> Result<DailyAlmanac, String> {
+    let bodies = bodies
+        .iter()
+        .filter(|b| get_planet_id(**b).is_some())
+        .map(|b| calculate_rise_set(*b, date, latitude, longitude, options))
+        .collect::<Result<Vec<_>, _>>()?;
+    let twilight = Twilight::all()
+        .into_iter()
+        .map(|t| calculate_twilight(t, date, latitude, longitude, options))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(DailyAlmanac {
+        date,
+        latitude,
+        longitude,
+        bodies,
+        twilight,
+    })
+}
+
+/// Minutes from the nearest sunrise to the birth (negative = born before sunrise)
+/// Used to check reported "born at sunrise" times; None if the Sun does not rise
+pub fn sunrise_offset_minutes(input: &ChartInput) -> Result<Option<f64>, String> {
+    let jd = calculate_jd(&input.datetime);
+    let options = input.horizon.unwrap_or_default();
+    let sunrise = next_rise_trans(
+        jd - 0.5,
+        CelestialBody::Sun,
+        SE_CALC_RISE,
+        input.latitude,
+        input.longitude,
+        &options,
+    )?;
+    Ok(sunrise
+        .filter(|rise| (jd - rise).abs() <= 0.5)
+        .map(|rise| (jd - rise) * 1440.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sweph::init_sweph;
+    use chrono::{TimeZone, Timelike};
+
+    #[test]
+    fn test_london_sunrise_and_twilight() {
+        init_sweph();
+        let date = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+        let options = HorizonOptions::default();
+        let almanac = calculate_daily_almanac(
+            date,
+            51.5074,
+            -0.1278,
+            &[CelestialBody::Sun, CelestialBody::Moon],
+            &options,
+        )
+        .unwrap();
+
+        // Midsummer in London: sunrise 03:43 UTC, sunset 20:21 UTC
+        let sun = &almanac.bodies[0];
+        let rise = sun.rise.unwrap();
+        let set = sun.set.unwrap();
+        assert_eq!((rise.hour(), rise.minute() / 10), (3, 4));
+        assert_eq!((set.hour(), set.minute() / 10), (20, 2));
+        assert!(sun.upper_culmination.unwrap() > rise && sun.upper_culmination.unwrap() < set);
+        assert!(!sun.circumpolar);
+        assert!(almanac.day_length().unwrap() > Duration::hours(16));
+
+        // Astronomical twilight never ends at midsummer in London
+        let civil = &almanac.twilight[0];
+        assert!(civil.dawn.unwrap() < rise && civil.dusk.unwrap() > set);
+        assert!(almanac.twilight[2].dawn.is_none());
+
+        let birth = Utc.with_ymd_and_hms(2024, 6, 21, 3, 50, 0).unwrap();
+        let offset = sunrise_offset_minutes(&ChartInput::new(birth, 51.5074, -0.1278))
+            .unwrap()
+            .unwrap();
+        assert!(offset > 0.0 && offset < 10.0);
+    }
+}
@@ -0,0 +1,466 @@
+use crate::calendar::{events_to_ics, CalendarEvent};
+use crate::chart::{CelestialBody, Chart, ZodiacSign};
+use crate::returns::{bisect_longitude, longitude_difference, scan_step};
+use crate::sweph::{calculate_jd, calculate_planet_speed, jd_to_datetime, ZodiacMode};
+use chrono::{DateTime, Utc};
+
+/// Longest retrograde shadow searched for (Pluto's is about six months)
+const MAX_SHADOW_DAYS: f64 = 400.0;
+
+/// Station calendar settings
+#[derive(Debug, Clone)]
+pub struct StationCalendarOptions {
+    pub bodies: Vec<CelestialBody>,
+    pub mode: ZodiacMode,     // Frame for reported degrees and sign ingresses
+    pub natal: Option<Chart>, // House ingresses are listed against these cusps
+}
+
+impl Default for StationCalendarOptions {
+    fn default() -> Self {
+        Self {
+            bodies: vec![
+                CelestialBody::Sun,
+                CelestialBody::Mercury,
+                CelestialBody::Venus,
+                CelestialBody::Mars,
+                CelestialBody::Jupiter,
+                CelestialBody::Saturn,
+                CelestialBody::Uranus,
+                CelestialBody::Neptune,
+                CelestialBody::Pluto,
+                CelestialBody::Chiron,
+            ],
+            mode: ZodiacMode::Sidereal,
+            natal: None,
+        }
+    }
+}
+
+impl StationCalendarOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_bodies(mut self, bodies: Vec<CelestialBody>) -> Self {
+        self.bodies = bodies;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: ZodiacMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_natal(mut self, natal: Chart) -> Self {
+        self.natal = Some(natal);
+        self
+    }
+}
+
+/// Direction a body turns at a station
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StationKind {
+    Retrograde,
+    Direct,
+}
+
+impl StationKind {
+    pub fn to_string(&self) -> &str {
+        match self {
+            StationKind::Retrograde => "retrograde",
+            StationKind::Direct => "direct",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Station {
+    pub body: CelestialBody,
+    pub kind: StationKind,
+    pub datetime: DateTime<Utc>,
+    pub longitude: f64,
+}
+
+/// A retrograde period with its shadow
+#[derive(Debug, Clone)]
+pub struct RetrogradeCycle {
+    pub body: CelestialBody,
+    pub pre_shadow: Option<DateTime<Utc>>, // First reaches the direct-station degree
+    pub station_retrograde: Station,
+    pub station_direct: Station,
+    pub post_shadow: Option<DateTime<Utc>>, // Back at the retrograde-station degree
+}
+
+#[derive(Debug, Clone)]
+pub struct SignIngress {
+    pub body: CelestialBody,
+    pub sign: ZodiacSign, // Sign entered
+    pub datetime: DateTime<Utc>,
+    pub retrograde: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct HouseIngress {
+    pub body: CelestialBody,
+    pub house: u8, // Natal house entered
+    pub datetime: DateTime<Utc>,
+    pub retrograde: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StationCalendar {
+    pub stations: Vec<Station>,
+    pub retrograde_cycles: Vec<RetrogradeCycle>,
+    pub sign_ingresses: Vec<SignIngress>,
+    pub house_ingresses: Vec<HouseIngress>,
+}
+
+/// "12 Aries"
+fn degree_text(longitude: f64) -> String {
+    format!(
+        "{} {}",
+        (longitude % 30.0).floor() as u32,
+        ZodiacSign::from_longitude(longitude).to_string()
+    )
+}
+
+impl StationCalendar {
+    /// Every entry as a dated line, in time order
+    pub fn entries(&self) -> Vec<(DateTime<Utc>, String)> {
+        let mut entries = Vec::new();
+
+        for station in &self.stations {
+            entries.push((
+                station.datetime,
+                format!(
+                    "{} stations {} at {}",
+                    station.body,
+                    station.kind.to_string(),
+                    degree_text(station.longitude)
+                ),
+            ));
+        }
+        for cycle in &self.retrograde_cycles {
+            let body = cycle.body.to_string();
+            if let Some(pre_shadow) = cycle.pre_shadow {
+                entries.push((
+                    pre_shadow,
+                    format!(
+                        "{} enters pre-retrograde shadow at {}",
+                        body,
+                        degree_text(cycle.station_direct.longitude)
+                    ),
+                ));
+            }
+            if let Some(post_shadow) = cycle.post_shadow {
+                entries.push((
+                    post_shadow,
+                    format!(
+                        "{} leaves post-retrograde shadow at {}",
+                        body,
+                        degree_text(cycle.station_retrograde.longitude)
+                    ),
+                ));
+            }
+        }
+        for ingress in &self.sign_ingresses {
+            entries.push((
+                ingress.datetime,
+                format!(
+                    "{} enters {}{}",
+                    ingress.body,
+                    ingress.sign.to_string(),
+                    if ingress.retrograde {
+                        " (retrograde)"
+                    } else {
+                        ""
+                    }
+                ),
+            ));
+        }
+        for ingress in &self.house_ingresses {
+            entries.push((
+                ingress.datetime,
+                format!(
+                    "{} enters natal house {}{}",
+                    ingress.body,
+                    ingress.house,
+                    if ingress.retrograde {
+                        " (retrograde)"
+                    } else {
+                        ""
+                    }
+                ),
+            ));
+        }
+
+        entries.sort_by_key(|(datetime, _)| *datetime);
+        entries
+    }
+
+    /// iCalendar export with one instantaneous event per entry
+    pub fn to_ics(&self) -> String {
+        let events: Vec<CalendarEvent> = self
+            .entries()
+            .into_iter()
+            .map(|(start, summary)| CalendarEvent {
+                start,
+                end: None,
+                summary,
+                description: String::new(),
+            })
+            .collect();
+        events_to_ics(&events, "Stations and Ingresses")
+    }
+}
+
+/// Bisect for the moment the daily speed changes sign in [low, high]
+fn bisect_station(
+    body: CelestialBody,
+    mut low: f64,
+    mut high: f64,
+    mode: ZodiacMode,
+) -> Result<f64, String> {
+    let low_retrograde = calculate_planet_speed(low, body, mode)?.1 < 0.0;
+    for _ in 0..50 {
+        let mid = (low + high) / 2.0;
+        if (calculate_planet_speed(mid, body, mode)?.1 < 0.0) == low_retrograde {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Ok((low + high) / 2.0)
+}
+
+/// Nearest crossing of `line` scanning from `from_jd` forwards (+1) or backwards (-1)
+fn find_crossing(
+    body: CelestialBody,
+    line: f64,
+    from_jd: f64,
+    direction: f64,
+    mode: ZodiacMode,
+) -> Result<Option<f64>, String> {
+    let step = scan_step(body) * direction;
+    let mut jd = from_jd;
+    let mut lon = calculate_planet_speed(jd, body, mode)?.0;
+
+    while (jd - from_jd).abs() < MAX_SHADOW_DAYS {
+        let next_jd = jd + step;
+        let next_lon = calculate_planet_speed(next_jd, body, mode)?.0;
+        let before = longitude_difference(line, lon);
+        let after = longitude_difference(line, next_lon);
+        if (before < 0.0) != (after < 0.0) && (before - after).abs() < 180.0 {
+            let (low, high) = if direction > 0.0 {
+                (jd, next_jd)
+            } else {
+                (next_jd, jd)
+            };
+            return Ok(Some(bisect_longitude(body, line, low, high, mode)?));
+        }
+        jd = next_jd;
+        lon = next_lon;
+    }
+    Ok(None)
+}
+
+/// Stations, retrograde shadows, sign ingresses and (with a natal chart) house ingresses
+/// between `start` and `end`
+pub fn calculate_station_calendar(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    options: &StationCalendarOptions,
+) -> Result<StationCalendar, String> {
+    let start_jd = calculate_jd(&start);
+    let end_jd = calculate_jd(&end);
+    if end_jd <= start_jd {
+        return Err("Calendar end must be after start".to_string());
+    }
+
+    let mode = options.mode;
+    let mut calendar = StationCalendar::default();
+
+    for body in options.bodies.iter().copied() {
+        if matches!(
+            body,
+            CelestialBody::Fortuna | CelestialBody::Vertex | CelestialBody::Lot(_)
+        ) {
+            continue;
+        }
+
+        let step = scan_step(body);
+        let mut jd = start_jd;
+        let (mut lon, mut speed) = calculate_planet_speed(jd, body, mode)?;
+        // Natal cusps are sidereal; house membership doesn't depend on the frame
+        let mut sidereal_lon = calculate_planet_speed(jd, body, ZodiacMode::Sidereal)?.0;
+        let mut body_stations = Vec::new();
+
+        while jd < end_jd {
+            let next_jd = (jd + step).min(end_jd);
+            let (next_lon, next_speed) = calculate_planet_speed(next_jd, body, mode)?;
+
+            // Stations
+            if (speed < 0.0) != (next_speed < 0.0) {
+                let root = bisect_station(body, jd, next_jd, mode)?;
+                body_stations.push(Station {
+                    body,
+                    kind: if next_speed < 0.0 {
+                        StationKind::Retrograde
+                    } else {
+                        StationKind::Direct
+                    },
+                    datetime: jd_to_datetime(root),
+                    longitude: calculate_planet_speed(root, body, mode)?.0,
+                });
+            }
+
+            // Sign ingresses
+            let sign_before = (lon / 30.0).floor();
+            let sign_after = (next_lon / 30.0).floor();
+            if sign_before != sign_after {
+                let boundary = if next_speed < 0.0 {
+                    sign_before * 30.0
+                } else {
+                    sign_after * 30.0
+                };
+                let root = bisect_longitude(body, boundary, jd, next_jd, mode)?;
+                calendar.sign_ingresses.push(SignIngress {
+                    body,
+                    sign: ZodiacSign::from_longitude(next_lon),
+                    datetime: jd_to_datetime(root),
+                    retrograde: calculate_planet_speed(root, body, mode)?.1 < 0.0,
+                });
+            }
+
+            // House ingresses against the natal cusps
+            let next_sidereal_lon = calculate_planet_speed(next_jd, body, ZodiacMode::Sidereal)?.0;
+            if let Some(natal) = &options.natal {
+                let house_before = natal.houses.get_house(sidereal_lon);
+                let house_after = natal.houses.get_house(next_sidereal_lon);
+                if house_before != house_after {
+                    let cusp = if next_speed < 0.0 {
+                        natal.houses.cusps[(house_before - 1) as usize]
+                    } else {
+                        natal.houses.cusps[(house_after - 1) as usize]
+                    };
+                    let root = bisect_longitude(body, cusp, jd, next_jd, ZodiacMode::Sidereal)?;
+                    calendar.house_ingresses.push(HouseIngress {
+                        body,
+                        house: house_after,
+                        datetime: jd_to_datetime(root),
+                        retrograde: next_speed < 0.0,
+                    });
+                }
+            }
+
+            jd = next_jd;
+            lon = next_lon;
+            speed = next_speed;
+            sidereal_lon = next_sidereal_lon;
+        }
+
+        // Pair each retrograde station with the following direct station. The nodes move
+        // retrograde by default, so their stations bound direct spells, not retrograde periods.
+        let normally_retrograde = matches!(
+            body,
+            CelestialBody::TrueNode | CelestialBody::MeanNode | CelestialBody::SouthNode
+        );
+        for pair in body_stations.windows(2).filter(|_| !normally_retrograde) {
+            let (retrograde, direct) = (&pair[0], &pair[1]);
+            if retrograde.kind != StationKind::Retrograde || direct.kind != StationKind::Direct {
+                continue;
+            }
+            let pre_shadow = find_crossing(
+                body,
+                direct.longitude,
+                calculate_jd(&retrograde.datetime),
+                -1.0,
+                mode,
+            )?;
+            let post_shadow = find_crossing(
+                body,
+                retrograde.longitude,
+                calculate_jd(&direct.datetime),
+                1.0,
+                mode,
+            )?;
+            calendar.retrograde_cycles.push(RetrogradeCycle {
+                body,
+                pre_shadow: pre_shadow.map(jd_to_datetime),
+                station_retrograde: retrograde.clone(),
+                station_direct: direct.clone(),
+                post_shadow: post_shadow.map(jd_to_datetime),
+            });
+        }
+
+        calendar.stations.extend(body_stations);
+    }
+
+    calendar.stations.sort_by_key(|s| s.datetime);
+    calendar.sign_ingresses.sort_by_key(|i| i.datetime);
+    calendar.house_ingresses.sort_by_key(|i| i.datetime);
+    Ok(calendar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sweph::init_sweph;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_mercury_retrograde_cycle() {
+        init_sweph();
+        let options = StationCalendarOptions::new()
+            .with_bodies(vec![CelestialBody::Mercury])
+            .with_mode(ZodiacMode::Tropical);
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let calendar = calculate_station_calendar(start, end, &options).unwrap();
+
+        // Mercury turns retrograde three or four times a year
+        assert!(calendar.retrograde_cycles.len() >= 3);
+
+        // 2024-04-01 22:14 UT retrograde station at 27°13' Aries (tropical)
+        let april = &calendar.retrograde_cycles[0];
+        let station = &april.station_retrograde;
+        assert_eq!(
+            station.datetime.format("%Y-%m-%d").to_string(),
+            "2024-04-01"
+        );
+        assert!((station.longitude - 27.2).abs() < 0.1);
+
+        // Shadow surrounds the retrograde period
+        assert!(april.pre_shadow.unwrap() < station.datetime);
+        assert!(april.post_shadow.unwrap() > april.station_direct.datetime);
+
+        // Tropical Mercury enters Aries in March 2024
+        assert!(calendar
+            .sign_ingresses
+            .iter()
+            .any(|i| i.sign == ZodiacSign::Aries
+                && i.datetime.format("%Y-%m").to_string() == "2024-03"));
+    }
+
+    #[test]
+    fn test_node_has_no_retrograde_cycles() {
+        init_sweph();
+        let options = StationCalendarOptions::new()
+            .with_bodies(vec![CelestialBody::TrueNode, CelestialBody::MeanNode])
+            .with_mode(ZodiacMode::Tropical);
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let calendar = calculate_station_calendar(start, end, &options).unwrap();
+
+        // The True Node wobbles direct briefly; those stations are still listed
+        assert!(calendar
+            .stations
+            .iter()
+            .any(|s| s.body == CelestialBody::TrueNode));
+        assert!(calendar
+            .stations
+            .iter()
+            .all(|s| s.body == CelestialBody::TrueNode));
+        assert!(calendar.retrograde_cycles.is_empty());
+    }
+}
@@ -0,0 +1,472 @@
+use crate::aspects::AspectEntity;
+use crate::chart::{AnglePoint, CelestialBody, Chart, Element, Modality, ZodiacSign};
+use std::fmt;
+
+/// Planets counted in the balance, hemispheres and chart shape
+pub const STATISTICS_PLANETS: [CelestialBody; 10] = [
+    CelestialBody::Sun,
+    CelestialBody::Moon,
+    CelestialBody::Mercury,
+    CelestialBody::Venus,
+    CelestialBody::Mars,
+    CelestialBody::Jupiter,
+    CelestialBody::Saturn,
+    CelestialBody::Uranus,
+    CelestialBody::Neptune,
+    CelestialBody::Pluto,
+];
+
+/// Weight of a point in the element and modality balance
+/// The lights and the Ascendant count double
+pub fn balance_weight(entity: &AspectEntity) -> f64 {
+    match entity {
+        AspectEntity::Body(CelestialBody::Sun | CelestialBody::Moon) => 2.0,
+        AspectEntity::Angle(AnglePoint::Ascendant) => 2.0,
+        _ => 1.0,
+    }
+}
+
+/// Half of the chart, split by the horizon or the meridian
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hemisphere {
+    Eastern,  // Houses 10-3, around the Ascendant
+    Western,  // Houses 4-9, around the Descendant
+    Northern, // Houses 1-6, below the horizon
+    Southern, // Houses 7-12, above the horizon
+}
+
+impl Hemisphere {
+    pub fn to_string(&self) -> &str {
+        match self {
+            Hemisphere::Eastern => "Eastern",
+            Hemisphere::Western => "Western",
+            Hemisphere::Northern => "Northern",
+            Hemisphere::Southern => "Southern",
+        }
+    }
+
+    pub fn contains_house(&self, house: u8) -> bool {
+        match self {
+            Hemisphere::Eastern => matches!(house, 10..=12 | 1..=3),
+            Hemisphere::Western => (4..=9).contains(&house),
+            Hemisphere::Northern => (1..=6).contains(&house),
+            Hemisphere::Southern => (7..=12).contains(&house),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HouseType {
+    Angular,   // 1, 4, 7, 10
+    Succedent, // 2, 5, 8, 11
+    Cadent,    // 3, 6, 9, 12
+}
+
+impl HouseType {
+    pub fn to_string(&self) -> &str {
+        match self {
+            HouseType::Angular => "Angular",
+            HouseType::Succedent => "Succedent",
+            HouseType::Cadent => "Cadent",
+        }
+    }
+
+    pub fn from_house(house: u8) -> Self {
+        match house % 3 {
+            1 => HouseType::Angular,
+            2 => HouseType::Succedent,
+            _ => HouseType::Cadent,
+        }
+    }
+}
+
+/// Marc Edmund Jones's planetary patterns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartShape {
+    Bundle,     // All planets within a trine
+    Bowl,       // All planets within an opposition
+    Bucket,     // A bowl with one planet as the handle opposite
+    Locomotive, // An empty trine, the rest evenly spread
+    Seesaw,     // Two opposing groups split by empty quarters
+    Splay,      // Irregular clusters
+    Splash,     // Planets spread around the whole wheel
+}
+
+impl ChartShape {
+    pub fn to_string(&self) -> &str {
+        match self {
+            ChartShape::Bundle => "Bundle",
+            ChartShape::Bowl => "Bowl",
+            ChartShape::Bucket => "Bucket",
+            ChartShape::Locomotive => "Locomotive",
+            ChartShape::Seesaw => "Seesaw",
+            ChartShape::Splay => "Splay",
+            ChartShape::Splash => "Splash",
+        }
+    }
+}
+
+/// What a lone planet stands alone in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SingletonKind {
+    Element(Element),
+    Modality(Modality),
+    Hemisphere(Hemisphere),
+    Handle, // The handle of a bucket
+}
+
+impl fmt::Display for SingletonKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SingletonKind::Element(element) => write!(f, "only planet in {}", element.to_string()),
+            SingletonKind::Modality(modality) => {
+                write!(f, "only planet in {}", modality.to_string())
+            }
+            SingletonKind::Hemisphere(hemisphere) => {
+                write!(f, "only planet in the {} hemisphere", hemisphere.to_string())
+            }
+            SingletonKind::Handle => f.write_str("handle of the bucket"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Singleton {
+    pub body: CelestialBody,
+    pub kind: SingletonKind,
+}
+
+/// Points falling in one element, modality, hemisphere, quadrant or house type
+#[derive(Debug, Clone)]
+pub struct Tally<K> {
+    pub key: K,
+    pub count: usize,
+    pub weight: f64,
+    pub points: Vec<AspectEntity>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChartStatistics {
+    pub elements: Vec<Tally<Element>>, // Planets plus Ascendant and MC, weighted
+    pub modalities: Vec<Tally<Modality>>, // Planets plus Ascendant and MC, weighted
+    pub hemispheres: Vec<Tally<Hemisphere>>, // Planets by house
+    pub quadrants: Vec<Tally<u8>>,     // 1 = houses 1-3 ... 4 = houses 10-12
+    pub house_types: Vec<Tally<HouseType>>,
+    pub shape: Option<ChartShape>, // Needs at least five planets
+    pub singletons: Vec<Singleton>,
+}
+
+impl ChartStatistics {
+    pub fn dominant_element(&self) -> Option<Element> {
+        dominant(&self.elements)
+    }
+
+    pub fn dominant_modality(&self) -> Option<Modality> {
+        dominant(&self.modalities)
+    }
+}
+
+/// Key with the strictly highest weight
+fn dominant<K: Copy>(tallies: &[Tally<K>]) -> Option<K> {
+    let best = tallies.iter().map(|t| t.weight).fold(0.0_f64, f64::max);
+    let mut leaders = tallies.iter().filter(|t| t.weight == best && best > 0.0);
+    match (leaders.next(), leaders.next()) {
+        (Some(leader), None) => Some(leader.key),
+        _ => None,
+    }
+}
+
+fn tally<K: Copy + PartialEq>(
+    keys: &[K],
+    points: &[(AspectEntity, K)],
+    weighted: bool,
+) -> Vec<Tally<K>> {
+    keys.iter()
+        .map(|key| {
+            let points: Vec<AspectEntity> = points
+                .iter()
+                .filter(|(_, k)| k == key)
+                .map(|(entity, _)| entity.clone())
+                .collect();
+            Tally {
+                key: *key,
+                count: points.len(),
+                weight: if weighted {
+                    points.iter().map(balance_weight).sum()
+                } else {
+                    points.len() as f64
+                },
+                points,
+            }
+        })
+        .collect()
+}
+
+/// Gaps between consecutive longitudes, sorted ascending; gap i follows longitude i
+fn gaps(sorted: &[f64]) -> Vec<f64> {
+    (0..sorted.len())
+        .map(|i| {
+            let next = sorted[(i + 1) % sorted.len()];
+            let gap = (next - sorted[i]).rem_euclid(360.0);
+            if sorted.len() == 1 {
+                360.0
+            } else {
+                gap
+            }
+        })
+        .collect()
+}
+
+/// Arc of the zodiac occupied by the longitudes (360 minus the widest gap)
+fn occupied_span(sorted: &[f64]) -> f64 {
+    360.0 - gaps(sorted).into_iter().fold(0.0, f64::max)
+}
+
+/// Jones shape of a set of longitudes, with the index of the bucket handle
+fn chart_shape(longitudes: &[f64]) -> Option<(ChartShape, Option<usize>)> {
+    if longitudes.len() < 5 {
+        return None;
+    }
+    let mut order: Vec<usize> = (0..longitudes.len()).collect();
+    order.sort_by(|a, b| longitudes[*a].partial_cmp(&longitudes[*b]).unwrap());
+    let sorted: Vec<f64> = order.iter().map(|i| longitudes[*i]).collect();
+    let gaps = gaps(&sorted);
+    let n = sorted.len();
+    let span = occupied_span(&sorted);
+
+    if span <= 120.0 {
+        return Some((ChartShape::Bundle, None));
+    }
+    if span <= 180.0 {
+        return Some((ChartShape::Bowl, None));
+    }
+
+    // A lone planet well away from a bowl of the others
+    for i in 0..n {
+        let before = gaps[(i + n - 1) % n];
+        let after = gaps[i];
+        let rest: Vec<f64> = sorted
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, lon)| *lon)
+            .collect();
+        if before >= 60.0 && after >= 60.0 && occupied_span(&rest) <= 180.0 {
+            return Some((ChartShape::Bucket, Some(order[i])));
+        }
+    }
+
+    // Two empty quarters with at least two planets on either side
+    let mut widest: Vec<usize> = (0..n).collect();
+    widest.sort_by(|a, b| gaps[*b].partial_cmp(&gaps[*a]).unwrap());
+    let (g1, g2) = (widest[0], widest[1]);
+    let apart = (g1 as isize - g2 as isize).rem_euclid(n as isize) as usize;
+    if gaps[g2] >= 90.0 && apart >= 2 && n - apart >= 2 {
+        return Some((ChartShape::Seesaw, None));
+    }
+
+    if span <= 240.0 {
+        return Some((ChartShape::Locomotive, None));
+    }
+
+    let mut signs: Vec<ZodiacSign> = Vec::new();
+    for lon in &sorted {
+        let sign = ZodiacSign::from_longitude(*lon);
+        if !signs.contains(&sign) {
+            signs.push(sign);
+        }
+    }
+    if signs.len() >= 8 {
+        Some((ChartShape::Splash, None))
+    } else {
+        Some((ChartShape::Splay, None))
+    }
+}
+
+/// Element, modality, hemisphere, quadrant and house-type balance, chart shape and singletons
+/// Uses the ten planets present in the chart; the Ascendant and MC count towards elements and
+/// modalities only. Planets without a house (house 0) are left out of the house-based tallies
+pub fn calculate_chart_statistics(chart: &Chart) -> ChartStatistics {
+    let planets: Vec<(CelestialBody, f64, u8)> = STATISTICS_PLANETS
+        .iter()
+        .filter_map(|body| chart.get_position(*body))
+        .map(|p| (p.body, p.longitude, p.house))
+        .collect();
+
+    let mut signs: Vec<(AspectEntity, ZodiacSign)> = planets
+        .iter()
+        .map(|(body, lon, _)| (AspectEntity::Body(*body), ZodiacSign::from_longitude(*lon)))
+        .collect();
+    signs.extend(
+        chart
+            .angles
+            .iter()
+            .map(|a| (AspectEntity::Angle(a.angle), a.sign())),
+    );
+    let elements: Vec<(AspectEntity, Element)> = signs
+        .iter()
+        .map(|(e, s)| (e.clone(), s.element()))
+        .collect();
+    let modalities: Vec<(AspectEntity, Modality)> = signs
+        .iter()
+        .map(|(e, s)| (e.clone(), s.modality()))
+        .collect();
+
+    let housed: Vec<(AspectEntity, u8)> = planets
+        .iter()
+        .filter(|(_, _, house)| (1..=12).contains(house))
+        .map(|(body, _, house)| (AspectEntity::Body(*body), *house))
+        .collect();
+    let all_hemispheres = [
+        Hemisphere::Eastern,
+        Hemisphere::Western,
+        Hemisphere::Northern,
+        Hemisphere::Southern,
+    ];
+    let hemispheres: Vec<(AspectEntity, Hemisphere)> = housed
+        .iter()
+        .flat_map(|(entity, house)| {
+            all_hemispheres
+                .iter()
+                .filter(|h| h.contains_house(*house))
+                .map(|h| (entity.clone(), *h))
+        })
+        .collect();
+    let quadrants: Vec<(AspectEntity, u8)> = housed
+        .iter()
+        .map(|(entity, house)| (entity.clone(), (house - 1) / 3 + 1))
+        .collect();
+    let house_types: Vec<(AspectEntity, HouseType)> = housed
+        .iter()
+        .map(|(entity, house)| (entity.clone(), HouseType::from_house(*house)))
+        .collect();
+
+    let elements = tally(
+        &[Element::Fire, Element::Earth, Element::Air, Element::Water],
+        &elements,
+        true,
+    );
+    let modalities = tally(
+        &[Modality::Cardinal, Modality::Fixed, Modality::Mutable],
+        &modalities,
+        true,
+    );
+    let hemispheres = tally(&all_hemispheres, &hemispheres, false);
+
+    let longitudes: Vec<f64> = planets.iter().map(|(_, lon, _)| *lon).collect();
+    let shape = chart_shape(&longitudes);
+
+    // Lone planets, counting planets only (not the angles)
+    let mut singletons = Vec::new();
+    let lone_planet = |points: &[AspectEntity]| {
+        let mut bodies = points.iter().filter_map(|p| match p {
+            AspectEntity::Body(body) => Some(*body),
+            _ => None,
+        });
+        match (bodies.next(), bodies.next()) {
+            (Some(body), None) => Some(body),
+            _ => None,
+        }
+    };
+    for t in &elements {
+        if let Some(body) = lone_planet(&t.points) {
+            singletons.push(Singleton {
+                body,
+                kind: SingletonKind::Element(t.key),
+            });
+        }
+    }
+    for t in &modalities {
+        if let Some(body) = lone_planet(&t.points) {
+            singletons.push(Singleton {
+                body,
+                kind: SingletonKind::Modality(t.key),
+            });
+        }
+    }
+    for t in &hemispheres {
+        if let Some(body) = lone_planet(&t.points) {
+            singletons.push(Singleton {
+                body,
+                kind: SingletonKind::Hemisphere(t.key),
+            });
+        }
+    }
+    if let Some((_, Some(handle))) = shape {
+        singletons.push(Singleton {
+            body: planets[handle].0,
+            kind: SingletonKind::Handle,
+        });
+    }
+
+    ChartStatistics {
+        elements,
+        modalities,
+        hemispheres,
+        quadrants: tally(&[1, 2, 3, 4], &quadrants, false),
+        house_types: tally(
+            &[HouseType::Angular, HouseType::Succedent, HouseType::Cadent],
+            &house_types,
+            false,
+        ),
+        shape: shape.map(|(shape, _)| shape),
+        singletons,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::{test_position, Position};
+
+    fn chart_with(longitudes: &[f64]) -> Chart {
+        let mut chart = Chart::new();
+        for (body, longitude) in STATISTICS_PLANETS.iter().zip(longitudes) {
+            chart.positions.push(Position {
+                house: (*longitude / 30.0) as u8 + 1, // Whole signs from 0° Aries
+                ..test_position(*body, *longitude)
+            });
+        }
+        chart
+    }
+
+    #[test]
+    fn test_balance_and_shapes() {
+        let bundle = chart_with(&[0.0, 10.0, 20.0, 35.0, 50.0, 70.0, 80.0, 95.0, 100.0, 110.0]);
+        let stats = calculate_chart_statistics(&bundle);
+        assert_eq!(stats.shape, Some(ChartShape::Bundle));
+        let fire = &stats.elements[0];
+        assert_eq!(fire.count, 3); // Sun, Moon and Mercury in Aries
+        assert_eq!(fire.weight, 5.0);
+        assert_eq!(stats.dominant_element(), Some(Element::Fire));
+        assert_eq!(stats.quadrants[0].count, 7);
+        assert_eq!(stats.hemispheres[1].count, 3); // Uranus, Neptune and Pluto in house 4
+        assert_eq!(stats.house_types[0].count, 6);
+
+        // Nine planets within 150°, Pluto alone across the wheel
+        let bucket = chart_with(&[
+            0.0, 15.0, 30.0, 45.0, 60.0, 80.0, 100.0, 120.0, 150.0, 255.0,
+        ]);
+        let stats = calculate_chart_statistics(&bucket);
+        assert_eq!(stats.shape, Some(ChartShape::Bucket));
+        assert!(stats.singletons.contains(&Singleton {
+            body: CelestialBody::Pluto,
+            kind: SingletonKind::Handle,
+        }));
+
+        let seesaw = chart_with(&[
+            0.0, 10.0, 20.0, 30.0, 40.0, 180.0, 190.0, 200.0, 210.0, 220.0,
+        ]);
+        assert_eq!(
+            calculate_chart_statistics(&seesaw).shape,
+            Some(ChartShape::Seesaw)
+        );
+        let splash = chart_with(&[
+            0.0, 36.0, 72.0, 108.0, 144.0, 180.0, 216.0, 252.0, 288.0, 324.0,
+        ]);
+        assert_eq!(
+            calculate_chart_statistics(&splash).shape,
+            Some(ChartShape::Splash)
+        );
+    }
+}
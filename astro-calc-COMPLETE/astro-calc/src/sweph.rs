@@ -1,5 +1,10 @@
-use crate::chart::{AnglePoint, AnglePosition, CelestialBody, Chart, HouseCusps, Position};
-use chrono::{DateTime, Datelike, Timelike, Utc};
+use crate::chart::{
+    AnglePoint, AnglePosition, CelestialBody, Chart, HouseCusps, HorizonPosition, Position, Sect,
+};
+use crate::fixed_stars::{calculate_fixed_star_report, FixedStarOptions};
+use crate::house_rulers::calculate_house_rulers;
+use crate::lots::{Lot, LotContext};
+use chrono::{DateTime, Datelike, TimeZone, Timelike, Utc};
 use std::ffi::CString;
 use std::os::raw::{c_char, c_double, c_int};
 
@@ -14,6 +19,14 @@ extern "C" {
         hour: c_double,
         gregflag: c_int,
     ) -> c_double;
+    pub fn swe_revjul(
+        jd: c_double,
+        gregflag: c_int,
+        jyear: *mut c_int,
+        jmon: *mut c_int,
+        jday: *mut c_int,
+        jut: *mut c_double,
+    );
     pub fn swe_calc_ut(
         tjd_ut: c_double,
         ipl: c_int,
@@ -41,6 +54,106 @@ extern "C" {
         ascmc_speed: *mut c_double,
         serr: *mut c_char,
     ) -> c_int;
+    pub fn swe_fixstar2_ut(
+        star: *mut c_char,
+        tjd_ut: c_double,
+        iflag: c_int,
+        xx: *mut c_double,
+        serr: *mut c_char,
+    ) -> c_int;
+    pub fn swe_fixstar2_mag(star: *mut c_char, mag: *mut c_double, serr: *mut c_char) -> c_int;
+    pub fn swe_azalt(
+        tjd_ut: c_double,
+        calc_flag: c_int,
+        geopos: *mut c_double,
+        atpress: c_double,
+        attemp: c_double,
+        xin: *mut c_double,
+        xaz: *mut c_double,
+    );
+    pub fn swe_houses_armc(
+        armc: c_double,
+        geolat: c_double,
+        eps: c_double,
+        hsys: c_int,
+        cusps: *mut c_double,
+        ascmc: *mut c_double,
+    ) -> c_int;
+    pub fn swe_get_ayanamsa_ex_ut(
+        tjd_ut: c_double,
+        iflag: c_int,
+        daya: *mut c_double,
+        serr: *mut c_char,
+    ) -> c_int;
+    pub fn swe_sol_eclipse_when_glob(
+        tjd_start: c_double,
+        ifl: c_int,
+        ifltype: c_int,
+        tret: *mut c_double,
+        backward: c_int,
+        serr: *mut c_char,
+    ) -> c_int;
+    pub fn swe_sol_eclipse_when_loc(
+        tjd_start: c_double,
+        ifl: c_int,
+        geopos: *mut c_double,
+        tret: *mut c_double,
+        attr: *mut c_double,
+        backward: c_int,
+        serr: *mut c_char,
+    ) -> c_int;
+    pub fn swe_sol_eclipse_where(
+        tjd_ut: c_double,
+        ifl: c_int,
+        geopos: *mut c_double,
+        attr: *mut c_double,
+        serr: *mut c_char,
+    ) -> c_int;
+    pub fn swe_sol_eclipse_how(
+        tjd_ut: c_double,
+        ifl: c_int,
+        geopos: *mut c_double,
+        attr: *mut c_double,
+        serr: *mut c_char,
+    ) -> c_int;
+    pub fn swe_lun_eclipse_when(
+        tjd_start: c_double,
+        ifl: c_int,
+        ifltype: c_int,
+        tret: *mut c_double,
+        backward: c_int,
+        serr: *mut c_char,
+    ) -> c_int;
+    pub fn swe_lun_eclipse_how(
+        tjd_ut: c_double,
+        ifl: c_int,
+        geopos: *mut c_double,
+        attr: *mut c_double,
+        serr: *mut c_char,
+    ) -> c_int;
+    pub fn swe_rise_trans(
+        tjd_ut: c_double,
+        ipl: c_int,
+        starname: *mut c_char,
+        epheflag: c_int,
+        rsmi: c_int,
+        geopos: *mut c_double,
+        atpress: c_double,
+        attemp: c_double,
+        tret: *mut c_double,
+        serr: *mut c_char,
+    ) -> c_int;
+    pub fn swe_heliacal_ut(
+        tjdstart_ut: c_double,
+        geopos: *mut c_double,
+        datm: *mut c_double,
+        dobs: *mut c_double,
+        object_name: *mut c_char,
+        type_event: c_int,
+        helflag: c_int,
+        dret: *mut c_double,
+        serr: *mut c_char,
+    ) -> c_int;
 }
 
 // Swiss Ephemeris constants
@@ -50,7 +163,35 @@ pub const SEFLG_SIDEREAL: c_int = 65536; // 0x10000 - CRITICAL: Must be 65536, N
 pub const SEFLG_SPEED: c_int = 256;
 pub const SEFLG_NONUT: c_int = 1024; // 0x400 - No nutation (for B1950 precision)
 pub const SEFLG_J2000: c_int = 2048; // 0x800 - J2000 coordinates
+pub const SEFLG_EQUATORIAL: c_int = 2048; // Right ascension / declination instead of ecliptic
 pub const SE_GREG_CAL: c_int = 1;
+pub const SE_ECL_NUT: c_int = -1; // swe_calc_ut "planet" returning obliquity and nutation
+pub const SE_ECL2HOR: c_int = 0; // swe_azalt input is ecliptic of date
+pub const SE_AST_OFFSET: c_int = 10000; // Numbered asteroids: SE_AST_OFFSET + catalogue number
+pub const ERIS_CATALOGUE_NUMBER: u32 = 136199;
+pub const SE_ECL_CENTRAL: c_int = 1;
+pub const SE_ECL_TOTAL: c_int = 4;
+pub const SE_ECL_ANNULAR: c_int = 8;
+pub const SE_ECL_PARTIAL: c_int = 16;
+pub const SE_ECL_ANNULAR_TOTAL: c_int = 32; // Hybrid
+pub const SE_ECL_PENUMBRAL: c_int = 64;
+pub const SE_ECL_VISIBLE: c_int = 128;
+pub const SE_CALC_RISE: c_int = 1;
+pub const SE_CALC_SET: c_int = 2;
+pub const SE_CALC_MTRANSIT: c_int = 4; // Upper culmination
+pub const SE_CALC_ITRANSIT: c_int = 8; // Lower culmination
+pub const SE_BIT_CIVIL_TWILIGHT: c_int = 1024; // Sun centre at -6°
+pub const SE_BIT_NAUTIC_TWILIGHT: c_int = 2048; // Sun centre at -12°
+pub const SE_BIT_ASTRO_TWILIGHT: c_int = 4096; // Sun centre at -18°
+pub const SE_HELIACAL_RISING: c_int = 1; // Morning first
+pub const SE_HELIACAL_SETTING: c_int = 2; // Evening last
+pub const SE_EVENING_FIRST: c_int = 3;
+pub const SE_MORNING_LAST: c_int = 4;
+pub const SE_ACRONYCHAL_RISING: c_int = 5;
+pub const SE_ACRONYCHAL_SETTING: c_int = 6;
+pub const SE_HELFLAG_HIGH_PRECISION: c_int = 256;
+pub const SE_HELFLAG_OPTICAL_PARAMS: c_int = 512;
+pub const SE_HELFLAG_AVKIND_VR: c_int = 65536; // Arcus visionis method (needed for acronychal events)
 
 // ═══════════════════════════════════════════════════════════════════════════
 // EMPIRICAL CALIBRATION CORRECTION
@@ -83,6 +224,50 @@ fn normalize_longitude(lon: f64) -> f64 {
     result
 }
 
+/// Zodiac frame used for reported longitudes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZodiacMode {
+    #[default]
+    Sidereal, // Fagan-Bradley, with calibration offset (matches natal positions)
+    Tropical,
+}
+
+impl ZodiacMode {
+    /// Swiss Ephemeris flags for ecliptic positions in this frame
+    pub(crate) fn flags(&self) -> c_int {
+        match self {
+            ZodiacMode::Sidereal => SEFLG_SIDEREAL | SEFLG_NONUT,
+            ZodiacMode::Tropical => SEFLG_NONUT,
+        }
+    }
+
+    /// Normalize a raw Swiss Ephemeris longitude for this frame
+    pub(crate) fn normalize(&self, lon: f64) -> f64 {
+        match self {
+            ZodiacMode::Sidereal => normalize_longitude(lon),
+            ZodiacMode::Tropical => lon.rem_euclid(360.0),
+        }
+    }
+}
+
+/// Observer conditions for horizon coordinates (refraction and parallax)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HorizonOptions {
+    pub pressure: f64,    // Atmospheric pressure in mbar (0 = estimate from elevation)
+    pub temperature: f64, // Air temperature in °C
+    pub elevation: f64,   // Observer height above sea level in metres
+}
+
+impl Default for HorizonOptions {
+    fn default() -> Self {
+        Self {
+            pressure: 1013.25,
+            temperature: 15.0,
+            elevation: 0.0,
+        }
+    }
+}
+
 /// Input data for chart calculation
 #[derive(Debug, Clone)]
 pub struct ChartInput {
@@ -91,6 +276,9 @@ pub struct ChartInput {
     pub longitude: f64,
     pub name: Option<String>,
     pub gender: Option<String>, // "Male", "Female", "Other"
+    pub bodies: Vec<CelestialBody>, // Bodies to compute and format, in output order
+    pub fixed_stars: Option<FixedStarOptions>, // None = no fixed star section
+    pub horizon: Option<HorizonOptions>, // None = no azimuth/altitude per position
 }
 
 impl ChartInput {
@@ -101,6 +289,9 @@ impl ChartInput {
             longitude,
             name: None,
             gender: None,
+            bodies: CelestialBody::all(),
+            fixed_stars: None,
+            horizon: None,
         }
     }
 
@@ -113,6 +304,24 @@ impl ChartInput {
         self.gender = Some(gender);
         self
     }
+
+    /// Replace the default body selection (e.g. `CelestialBody::all()` plus extras)
+    pub fn with_bodies(mut self, bodies: Vec<CelestialBody>) -> Self {
+        self.bodies = bodies;
+        self
+    }
+
+    /// Include fixed star positions, conjunctions and parans in the chart
+    pub fn with_fixed_stars(mut self, options: FixedStarOptions) -> Self {
+        self.fixed_stars = Some(options);
+        self
+    }
+
+    /// Add azimuth and altitude to every position for the birth place
+    pub fn with_horizon(mut self, options: HorizonOptions) -> Self {
+        self.horizon = Some(options);
+        self
+    }
 }
 
 /// Chart calculation mode
@@ -134,7 +343,7 @@ pub fn init_sweph() {
 }
 
 /// Get planet ID for Swiss Ephemeris
-fn get_planet_id(body: CelestialBody) -> Option<c_int> {
+pub(crate) fn get_planet_id(body: CelestialBody) -> Option<c_int> {
     match body {
         CelestialBody::Sun => Some(0),
         CelestialBody::Moon => Some(1),
@@ -146,14 +355,24 @@ fn get_planet_id(body: CelestialBody) -> Option<c_int> {
         CelestialBody::Uranus => Some(7),
         CelestialBody::Neptune => Some(8),
         CelestialBody::Pluto => Some(9),
+        CelestialBody::MeanNode => Some(10),
         CelestialBody::TrueNode => Some(11),
+        CelestialBody::MeanLilith => Some(12),
+        CelestialBody::TrueLilith => Some(13),
         CelestialBody::Chiron => Some(15),
-        _ => None, // Fortuna and Vertex calculated separately
+        CelestialBody::Pholus => Some(16),
+        CelestialBody::Ceres => Some(17),
+        CelestialBody::Pallas => Some(18),
+        CelestialBody::Juno => Some(19),
+        CelestialBody::Vesta => Some(20),
+        CelestialBody::Eris => Some(SE_AST_OFFSET + ERIS_CATALOGUE_NUMBER as c_int),
+        CelestialBody::Asteroid(number) => Some(SE_AST_OFFSET + number as c_int),
+        _ => None, // Fortuna, Vertex and South Node calculated separately
     }
 }
 
 /// Calculate Julian Day from DateTime
-fn calculate_jd(dt: &DateTime<Utc>) -> c_double {
+pub(crate) fn calculate_jd(dt: &DateTime<Utc>) -> c_double {
     unsafe {
         swe_julday(
             dt.year() as c_int,
@@ -167,8 +386,36 @@ fn calculate_jd(dt: &DateTime<Utc>) -> c_double {
     }
 }
 
+/// Convert a Julian Day (UT) back to a DateTime, to the nearest second
+pub(crate) fn jd_to_datetime(jd: c_double) -> DateTime<Utc> {
+    let (mut year, mut month, mut day, mut hour) = (0, 0, 0, 0.0);
+    unsafe {
+        swe_revjul(jd, SE_GREG_CAL, &mut year, &mut month, &mut day, &mut hour);
+    }
+
+    let seconds = (hour * 3600.0).round() as i64;
+    let midnight = Utc.with_ymd_and_hms(year, month as u32, day as u32, 0, 0, 0).unwrap();
+    midnight + chrono::Duration::seconds(seconds)
+}
+
 /// Calculate a single planet position
-fn calculate_planet(jd: c_double, body: CelestialBody) -> Result<(f64, bool), String> {
+pub(crate) fn calculate_planet(jd: c_double, body: CelestialBody) -> Result<(f64, bool), String> {
+    let (longitude, speed) = calculate_planet_speed(jd, body, ZodiacMode::Sidereal)?;
+    Ok((longitude, speed < 0.0))
+}
+
+/// Longitude and daily speed (degrees/day) of a body in the given frame
+pub(crate) fn calculate_planet_speed(
+    jd: c_double,
+    body: CelestialBody,
+    mode: ZodiacMode,
+) -> Result<(f64, f64), String> {
+    // South Node is always exactly opposite the True Node
+    if matches!(body, CelestialBody::SouthNode) {
+        let (north, speed) = calculate_planet_speed(jd, CelestialBody::TrueNode, mode)?;
+        return Ok(((north + 180.0) % 360.0, speed));
+    }
+
     let planet_id = get_planet_id(body).ok_or("Cannot calculate this body directly")?;
 
     let mut xx = [0.0; 6];
@@ -178,20 +425,35 @@ fn calculate_planet(jd: c_double, body: CelestialBody) -> Result<(f64, bool), St
         swe_calc_ut(
             jd,
             planet_id,
-            SEFLG_SIDEREAL | SEFLG_SPEED | SEFLG_NONUT,
+            mode.flags() | SEFLG_SPEED,
             xx.as_mut_ptr(),
             serr.as_mut_ptr(),
         )
     };
 
     if result < 0 {
-        return Err(format!("Failed to calculate {}", body.to_string()));
+        // Asteroids fail here when their .se1 file is missing from the ephemeris path
+        let message = unsafe { std::ffi::CStr::from_ptr(serr.as_ptr()) };
+        return Err(format!(
+            "Failed to calculate {}: {}",
+            body,
+            message.to_string_lossy()
+        ));
     }
 
-    let longitude = normalize_longitude(xx[0]);
-    let speed = xx[3];
+    Ok((mode.normalize(xx[0]), xx[3]))
+}
 
-    Ok((longitude, speed < 0.0))
+/// Swiss Ephemeris expects longitude as east-positive
+/// Western longitudes must be negative
+pub(crate) fn east_longitude(longitude: f64) -> f64 {
+    if longitude > 180.0 {
+        longitude - 360.0
+    } else if longitude < -180.0 {
+        longitude + 360.0
+    } else {
+        longitude
+    }
 }
 
 /// Calculate houses and angles
@@ -242,33 +504,208 @@ fn calculate_houses(
     Ok((HouseCusps::new(house_cusps), asc, mc, vertex))
 }
 
-/// Determine if Sun is above horizon (day birth)
-/// Sun in houses 7-12 = day birth (above horizon)
-/// Sun in houses 1-6 = night birth (below horizon)
-fn is_sun_above_horizon(sun_house: u8) -> bool {
-    // Day birth if Sun is in houses 7, 8, 9, 10, 11, or 12
-    sun_house >= 7
+/// Tropical ecliptic coordinates of date (lon, lat, distance) for swe_azalt
+fn tropical_ecliptic(jd: c_double, body: CelestialBody) -> Result<[f64; 3], String> {
+    let planet_id = get_planet_id(body).ok_or("Cannot calculate this body directly")?;
+
+    let mut xx = [0.0; 6];
+    let mut serr = [0i8; 256];
+    let result = unsafe { swe_calc_ut(jd, planet_id, 0, xx.as_mut_ptr(), serr.as_mut_ptr()) };
+    if result < 0 {
+        return Err(format!("Failed to calculate {}", body));
+    }
+
+    Ok([xx[0], xx[1], xx[2]])
+}
+
+/// Fagan-Bradley ayanamsa for the flags used by sidereal positions
+fn ayanamsa(jd: c_double) -> Result<f64, String> {
+    let mut ayanamsa = 0.0;
+    let mut serr = [0i8; 256];
+    let result = unsafe {
+        swe_get_ayanamsa_ex_ut(jd, SEFLG_SIDEREAL | SEFLG_NONUT, &mut ayanamsa, serr.as_mut_ptr())
+    };
+    if result < 0 {
+        return Err("Failed to calculate ayanamsa".to_string());
+    }
+    Ok(ayanamsa)
+}
+
+/// Convert a calibrated sidereal longitude back to a tropical one (for ecliptic points)
+pub(crate) fn sidereal_to_tropical(jd: c_double, longitude: f64) -> Result<f64, String> {
+    Ok((longitude - CALIBRATION_OFFSET_DEGREES + ayanamsa(jd)?).rem_euclid(360.0))
+}
+
+/// Convert a tropical longitude to a calibrated sidereal one
+pub(crate) fn tropical_to_sidereal(jd: c_double, longitude: f64) -> Result<f64, String> {
+    Ok(normalize_longitude((longitude - ayanamsa(jd)?).rem_euclid(360.0)))
+}
+
+/// True obliquity of the ecliptic
+fn obliquity(jd: c_double) -> Result<f64, String> {
+    let mut xx = [0.0; 6];
+    let mut serr = [0i8; 256];
+    let result = unsafe { swe_calc_ut(jd, SE_ECL_NUT, 0, xx.as_mut_ptr(), serr.as_mut_ptr()) };
+    if result < 0 {
+        return Err("Failed to calculate obliquity".to_string());
+    }
+    Ok(xx[0])
+}
+
+/// Right ascension of the MC (sidereal time in degrees) at a place
+pub(crate) fn calculate_armc(jd: c_double, lat: f64, lon_east: f64) -> Result<f64, String> {
+    let mut cusps = [0.0; 13];
+    let mut ascmc = [0.0; 10];
+    let mut cusp_speed = [0.0; 13];
+    let mut ascmc_speed = [0.0; 10];
+    let mut serr = [0i8; 256];
+
+    let result = unsafe {
+        swe_houses_ex2(
+            jd,
+            0,
+            lat,
+            lon_east,
+            PLACIDUS_HOUSE_SYSTEM as c_int,
+            cusps.as_mut_ptr(),
+            ascmc.as_mut_ptr(),
+            cusp_speed.as_mut_ptr(),
+            ascmc_speed.as_mut_ptr(),
+            serr.as_mut_ptr(),
+        )
+    };
+    if result < 0 {
+        return Err("Failed to calculate houses".to_string());
+    }
+    Ok(ascmc[2])
+}
+
+/// ARMC that puts the given (calibrated sidereal) longitude on the MC
+pub(crate) fn mc_to_armc(jd: c_double, mc: f64) -> Result<f64, String> {
+    let lambda = sidereal_to_tropical(jd, mc)?.to_radians();
+    let eps = obliquity(jd)?.to_radians();
+    let armc = (lambda.sin() * eps.cos()).atan2(lambda.cos()).to_degrees();
+    Ok(armc.rem_euclid(360.0))
+}
+
+/// Houses and angles (cusps, ASC, MC, Vertex) for a given ARMC, in the chart's sidereal frame
+pub(crate) fn calculate_houses_from_armc(
+    jd: c_double,
+    armc: f64,
+    lat: f64,
+) -> Result<(HouseCusps, f64, f64, f64), String> {
+    let mut cusps = [0.0; 13];
+    let mut ascmc = [0.0; 10];
+
+    let result = unsafe {
+        swe_houses_armc(
+            armc,
+            lat,
+            obliquity(jd)?,
+            PLACIDUS_HOUSE_SYSTEM as c_int,
+            cusps.as_mut_ptr(),
+            ascmc.as_mut_ptr(),
+        )
+    };
+    if result < 0 {
+        return Err("Failed to calculate houses".to_string());
+    }
+
+    let mut house_cusps = [0.0; 12];
+    for i in 0..12 {
+        house_cusps[i] = tropical_to_sidereal(jd, cusps[i + 1])?;
+    }
+
+    Ok((
+        HouseCusps::new(house_cusps),
+        tropical_to_sidereal(jd, ascmc[0])?,
+        tropical_to_sidereal(jd, ascmc[1])?,
+        tropical_to_sidereal(jd, ascmc[3])?,
+    ))
+}
+
+/// Azimuth and altitude of an ecliptic position for an observer
+fn horizon_coordinates(
+    jd: c_double,
+    lat: f64,
+    lon_east: f64,
+    ecliptic: [f64; 3],
+    options: &HorizonOptions,
+) -> HorizonPosition {
+    let mut geopos = [lon_east, lat, options.elevation];
+    let mut xin = ecliptic;
+    let mut xaz = [0.0; 3];
+    unsafe {
+        swe_azalt(
+            jd,
+            SE_ECL2HOR,
+            geopos.as_mut_ptr(),
+            options.pressure,
+            options.temperature,
+            xin.as_mut_ptr(),
+            xaz.as_mut_ptr(),
+        );
+    }
+
+    HorizonPosition {
+        // Swiss Ephemeris measures azimuth from south; report it from north like a compass
+        azimuth: (xaz[0] + 180.0) % 360.0,
+        true_altitude: xaz[1],
+        apparent_altitude: xaz[2],
+        above_horizon: xaz[2] > 0.0,
+    }
+}
+
+/// Horizon coordinates for a chart position (planets directly, points via their longitude)
+fn calculate_horizon(
+    jd: c_double,
+    lat: f64,
+    lon_east: f64,
+    pos: &Position,
+    options: &HorizonOptions,
+) -> Result<HorizonPosition, String> {
+    let ecliptic = match pos.body {
+        CelestialBody::SouthNode => {
+            let north = tropical_ecliptic(jd, CelestialBody::TrueNode)?;
+            [(north[0] + 180.0) % 360.0, 0.0, north[2]]
+        }
+        body if get_planet_id(body).is_some() => tropical_ecliptic(jd, body)?,
+        // Fortuna, lots and Vertex are points on the ecliptic
+        _ => [sidereal_to_tropical(jd, pos.longitude)?, 0.0, 1.0],
+    };
+
+    Ok(horizon_coordinates(jd, lat, lon_east, ecliptic, options))
+}
+
+/// Determine day/night sect from the Sun's true altitude
+/// Works for any house system and for Suns a few degrees from the horizon
+fn calculate_sect(jd: c_double, lat: f64, lon_east: f64) -> Result<Sect, String> {
+    let sun = tropical_ecliptic(jd, CelestialBody::Sun)
+        .map_err(|_| "Failed to calculate Sun altitude".to_string())?;
+
+    // No refraction: sect uses the true (geometric) altitude
+    let options = HorizonOptions {
+        pressure: 0.0,
+        temperature: 0.0,
+        elevation: 0.0,
+    };
+    let horizon = horizon_coordinates(jd, lat, lon_east, sun, &options);
+
+    // Day birth if the Sun's center is on or above the horizon
+    Ok(if horizon.true_altitude >= 0.0 { Sect::Day } else { Sect::Night })
 }
 
 /// Calculate a complete natal chart
 pub fn calculate_chart(input: &ChartInput) -> Result<Chart, String> {
     let jd = calculate_jd(&input.datetime);
-    
-    // Swiss Ephemeris expects longitude as east-positive
-    // Western longitudes must be negative
-    let lon_east = if input.longitude > 180.0 {
-        input.longitude - 360.0
-    } else if input.longitude < -180.0 {
-        input.longitude + 360.0
-    } else {
-        input.longitude
-    };
+    let lon_east = east_longitude(input.longitude);
     
     let (houses, asc, mc, vertex) = calculate_houses(jd, input.latitude, lon_east)?;
 
     let mut chart = Chart::new()
         .with_metadata(input.name.clone(), input.gender.clone());
     chart.houses = houses;
+    chart.sect = Some(calculate_sect(jd, input.latitude, lon_east)?);
 
     // Add angles
     chart.angles.push(AnglePosition {
@@ -280,68 +717,81 @@ pub fn calculate_chart(input: &ChartInput) -> Result<Chart, String> {
         longitude: mc,
     });
 
-    // Calculate all planets
-    let mut sun_lon = 0.0;
-    let mut sun_house = 1u8;
-    let mut moon_lon = 0.0;
-
-    for body in CelestialBody::all() {
-        if matches!(body, CelestialBody::Fortuna | CelestialBody::Vertex) {
+    // Calculate all selected planets
+    for body in input.bodies.iter().copied() {
+        if matches!(body, CelestialBody::Fortuna | CelestialBody::Vertex | CelestialBody::Lot(_)) {
             continue;
         }
 
         let (longitude, retrograde) = calculate_planet(jd, body)?;
 
-        if matches!(body, CelestialBody::Sun) {
-            sun_lon = longitude;
-        }
-        if matches!(body, CelestialBody::Moon) {
-            moon_lon = longitude;
-        }
-
-        let house = chart.houses.get_house(longitude);
-        
-        if matches!(body, CelestialBody::Sun) {
-            sun_house = house;
-        }
-
         chart.positions.push(Position {
             body,
             longitude,
             retrograde,
-            house,
+            house: chart.houses.get_house(longitude),
+            horizon: None,
         });
     }
 
-    // Calculate Part of Fortune
-    // Day birth (Sun above horizon): ASC + Moon - Sun
-    // Night birth (Sun below horizon): ASC + Sun - Moon
-    let is_day_birth = is_sun_above_horizon(sun_house);
-    
-    let mut fortuna_lon = if is_day_birth {
-        (asc + moon_lon - sun_lon) % 360.0
-    } else {
-        (asc + sun_lon - moon_lon) % 360.0
-    };
-    
-    if fortuna_lon < 0.0 {
-        fortuna_lon += 360.0;
+    // Calculate selected lots (Fortune first, then the rest in selection order)
+    let mut lots: Vec<Lot> = Vec::new();
+    if input.bodies.contains(&CelestialBody::Fortuna) {
+        lots.push(Lot::Fortune);
+    }
+    for body in &input.bodies {
+        if let CelestialBody::Lot(lot) = body {
+            lots.push(*lot);
+        }
     }
 
-    chart.positions.push(Position {
-        body: CelestialBody::Fortuna,
-        longitude: fortuna_lon,
-        retrograde: false,
-        house: chart.houses.get_house(fortuna_lon),
-    });
+    if !lots.is_empty() {
+        // Lots need their operands even when they are not part of the selection
+        let fallback = |body: CelestialBody| calculate_planet(jd, body).map(|(lon, _)| lon);
+
+        // Day birth (Sun above horizon) vs night birth decides formula reversal
+        let is_day_birth = chart.sect == Some(Sect::Day);
+
+        let mut lot_positions = Vec::new();
+        {
+            let context = LotContext::new(&chart, is_day_birth).with_fallback(&fallback);
+            for lot in lots {
+                let longitude = context.evaluate(&lot.formula())?;
+                lot_positions.push(Position {
+                    body: lot.body(),
+                    longitude,
+                    retrograde: false,
+                    house: chart.houses.get_house(longitude),
+                    horizon: None,
+                });
+            }
+        }
+        chart.positions.extend(lot_positions);
+    }
 
     // Add Vertex
-    chart.positions.push(Position {
-        body: CelestialBody::Vertex,
-        longitude: vertex,
-        retrograde: false,
-        house: chart.houses.get_house(vertex),
-    });
+    if input.bodies.contains(&CelestialBody::Vertex) {
+        chart.positions.push(Position {
+            body: CelestialBody::Vertex,
+            longitude: vertex,
+            retrograde: false,
+            house: chart.houses.get_house(vertex),
+            horizon: None,
+        });
+    }
+
+    if let Some(options) = &input.horizon {
+        for i in 0..chart.positions.len() {
+            let horizon = calculate_horizon(jd, input.latitude, lon_east, &chart.positions[i], options)?;
+            chart.positions[i].horizon = Some(horizon);
+        }
+    }
+
+    if let Some(options) = &input.fixed_stars {
+        chart.fixed_stars = Some(calculate_fixed_star_report(jd, input.latitude, &chart, options)?);
+    }
+
+    chart.house_rulers = Some(calculate_house_rulers(&chart));
 
     Ok(chart)
 }
@@ -364,9 +814,9 @@ pub fn calculate_transit_chart(
     transit_chart.angles = natal_chart.angles.clone(); // Use natal angles
 
     // Calculate transiting planets (no Fortuna or Vertex in transits)
-    for body in CelestialBody::all() {
+    for body in natal.bodies.iter().copied() {
         // Skip calculated points in transits
-        if matches!(body, CelestialBody::Fortuna | CelestialBody::Vertex) {
+        if matches!(body, CelestialBody::Fortuna | CelestialBody::Vertex | CelestialBody::Lot(_)) {
             continue;
         }
 
@@ -378,6 +828,7 @@ pub fn calculate_transit_chart(
             longitude,
             retrograde,
             house, // Which natal house the transiting planet is in
+            horizon: None,
         });
     }
 
@@ -405,6 +856,10 @@ pub fn calculate_synastry_charts(
         pos.house = chart1.houses.get_house(pos.longitude);
     }
 
+    // Each ruler report placed rulers in the person's own houses, which no longer match
+    chart1.house_rulers = None;
+    chart2.house_rulers = None;
+
     Ok((chart1, chart2))
 }
 
@@ -423,5 +878,80 @@ mod tests {
         let dt = Utc::now();
         let jd = calculate_jd(&dt);
         assert!(jd > 2400000.0); // Reasonable JD for modern dates
+
+        let dt = Utc.with_ymd_and_hms(1990, 6, 15, 14, 30, 5).unwrap();
+        assert_eq!(jd_to_datetime(calculate_jd(&dt)), dt);
+    }
+
+    #[test]
+    fn test_south_node_opposes_true_node() {
+        init_sweph();
+        let input = ChartInput::new(Utc::now(), 40.7128, -74.0060).with_bodies(vec![
+            CelestialBody::TrueNode,
+            CelestialBody::SouthNode,
+            CelestialBody::MeanLilith,
+        ]);
+        let chart = calculate_chart(&input).unwrap();
+        assert_eq!(chart.positions.len(), 3);
+
+        let north = chart.get_position(CelestialBody::TrueNode).unwrap().longitude;
+        let south = chart.get_position(CelestialBody::SouthNode).unwrap().longitude;
+        assert!((((south - north) + 360.0) % 360.0 - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_synastry_drops_house_rulers() {
+        init_sweph();
+        let birth1 = Utc.with_ymd_and_hms(1985, 3, 10, 8, 15, 0).unwrap();
+        let birth2 = Utc.with_ymd_and_hms(1990, 9, 1, 12, 0, 0).unwrap();
+        let person1 = ChartInput::new(birth1, 51.5074, -0.1278);
+        let person2 = ChartInput::new(birth2, 40.7128, -74.0060);
+        assert!(calculate_chart(&person1).unwrap().house_rulers.is_some());
+
+        // Positions now sit in the partner's houses, so neither report would be true
+        let (chart1, chart2) = calculate_synastry_charts(&person1, &person2).unwrap();
+        assert!(chart1.house_rulers.is_none());
+        assert!(chart2.house_rulers.is_none());
+    }
+
+    #[test]
+    fn test_sect_from_sun_altitude() {
+        init_sweph();
+        // New York, June solstice: local noon vs local 1am
+        let noon = Utc.with_ymd_and_hms(2020, 6, 21, 17, 0, 0).unwrap();
+        let night = Utc.with_ymd_and_hms(2020, 6, 21, 5, 0, 0).unwrap();
+        let jd_noon = calculate_jd(&noon);
+        let jd_night = calculate_jd(&night);
+        assert_eq!(calculate_sect(jd_noon, 40.7128, -74.0060).unwrap(), Sect::Day);
+        assert_eq!(calculate_sect(jd_night, 40.7128, -74.0060).unwrap(), Sect::Night);
+    }
+
+    #[test]
+    fn test_sun_horizon_around_sunrise() {
+        init_sweph();
+        // New York, June solstice: sunrise is at about 09:25 UTC, north of east
+        let sun_at = |hour: u32, minute: u32| {
+            let input = ChartInput::new(
+                Utc.with_ymd_and_hms(2020, 6, 21, hour, minute, 0).unwrap(),
+                40.7128,
+                -74.0060,
+            )
+            .with_bodies(vec![CelestialBody::Sun])
+            .with_horizon(HorizonOptions::default());
+            let chart = calculate_chart(&input).unwrap();
+            chart.positions[0].horizon.unwrap()
+        };
+
+        let before = sun_at(9, 5);
+        let after = sun_at(9, 45);
+        assert!(!before.above_horizon && before.true_altitude < 0.0);
+        assert!(after.above_horizon && after.true_altitude > 0.0);
+        assert!(after.azimuth > 50.0 && after.azimuth < 70.0);
+        // Refraction lifts the Sun near the horizon
+        assert!(after.apparent_altitude > after.true_altitude);
+
+        let noon = sun_at(17, 0);
+        assert!(noon.true_altitude > 70.0);
+        assert!((noon.azimuth - 180.0).abs() < 10.0);
     }
 }
@@ -0,0 +1,376 @@
+use crate::aspects::{AspectEntity, AspectType};
+use crate::calendar::{csv_field, events_to_ics, CalendarEvent};
+use crate::chart::CelestialBody;
+use crate::returns::{bisect_longitude, longitude_difference, scan_step};
+use crate::sweph::{
+    calculate_chart, calculate_jd, calculate_planet_speed, jd_to_datetime, ChartInput, ZodiacMode,
+};
+use chrono::{DateTime, Utc};
+
+/// Transit search settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitSearchOptions {
+    pub bodies: Vec<CelestialBody>, // Transiting bodies
+    pub aspects: Vec<AspectType>,
+    pub orb: f64, // Degrees either side of exact
+}
+
+impl Default for TransitSearchOptions {
+    fn default() -> Self {
+        Self {
+            bodies: CelestialBody::all()
+                .into_iter()
+                .filter(|b| !matches!(b, CelestialBody::Fortuna | CelestialBody::Vertex))
+                .collect(),
+            aspects: vec![
+                AspectType::Conjunction,
+                AspectType::Sextile,
+                AspectType::Square,
+                AspectType::Trine,
+                AspectType::Opposition,
+            ],
+            orb: 1.0,
+        }
+    }
+}
+
+impl TransitSearchOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_bodies(mut self, bodies: Vec<CelestialBody>) -> Self {
+        self.bodies = bodies;
+        self
+    }
+
+    pub fn with_aspects(mut self, aspects: Vec<AspectType>) -> Self {
+        self.aspects = aspects;
+        self
+    }
+
+    pub fn with_orb(mut self, orb: f64) -> Self {
+        self.orb = orb;
+        self
+    }
+}
+
+/// One visit of a transiting body to an aspect of a natal point
+#[derive(Debug, Clone)]
+pub struct TransitEvent {
+    pub transiting: CelestialBody,
+    pub natal: AspectEntity,
+    pub aspect_type: AspectType,
+    pub enter_orb: Option<DateTime<Utc>>, // None = already in orb at the start of the search
+    pub exact: Vec<DateTime<Utc>>,        // Exact hits (up to 3 with retrograde passes)
+    pub leave_orb: Option<DateTime<Utc>>, // None = still in orb at the end of the search
+    pub search_start: DateTime<Utc>,
+    pub search_end: DateTime<Utc>,
+}
+
+impl TransitEvent {
+    /// Time used to order the calendar (the search start if already in orb)
+    pub fn sort_time(&self) -> DateTime<Utc> {
+        self.enter_orb.unwrap_or(self.search_start)
+    }
+
+    /// End of the visit, clipped to the search end if still in orb
+    pub fn end_time(&self) -> DateTime<Utc> {
+        self.leave_orb.unwrap_or(self.search_end)
+    }
+
+    pub fn description(&self) -> String {
+        format!(
+            "Transiting {} {} natal {}",
+            self.transiting,
+            self.aspect_type.symbol(),
+            self.natal
+        )
+    }
+}
+
+/// Where a crossing of an aspect line happened
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Crossing {
+    Exact,
+    OrbEdge,
+}
+
+/// Find every transit-to-natal aspect within orb between `start` and `end`
+/// Natal points are the natal chart's positions and angles; results are chronological
+pub fn search_transits(
+    natal: &ChartInput,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    options: &TransitSearchOptions,
+) -> Result<Vec<TransitEvent>, String> {
+    let start_jd = calculate_jd(&start);
+    let end_jd = calculate_jd(&end);
+    if end_jd <= start_jd {
+        return Err("Search end must be after start".to_string());
+    }
+
+    let natal_chart = calculate_chart(natal)?;
+    let natal_points = natal_chart.points();
+
+    let mode = ZodiacMode::Sidereal;
+    let mut events = Vec::new();
+
+    for body in options.bodies.iter().copied() {
+        if matches!(
+            body,
+            CelestialBody::Fortuna | CelestialBody::Vertex | CelestialBody::Lot(_)
+        ) {
+            continue;
+        }
+
+        // Sample the transiting body once for all natal points
+        let step = scan_step(body);
+        let mut samples = Vec::new();
+        let mut jd = start_jd;
+        loop {
+            samples.push((jd, calculate_planet_speed(jd, body, mode)?.0));
+            if jd >= end_jd {
+                break;
+            }
+            jd = (jd + step).min(end_jd);
+        }
+
+        for (entity, natal_lon) in &natal_points {
+            for aspect_type in options.aspects.iter().copied() {
+                let angle = aspect_type.angle();
+                let mut targets = vec![(natal_lon + angle).rem_euclid(360.0)];
+                if angle > 0.0 && angle < 180.0 {
+                    targets.push((natal_lon - angle).rem_euclid(360.0));
+                }
+
+                for target in targets {
+                    let lines = [
+                        (target, Crossing::Exact),
+                        ((target - options.orb).rem_euclid(360.0), Crossing::OrbEdge),
+                        ((target + options.orb).rem_euclid(360.0), Crossing::OrbEdge),
+                    ];
+
+                    let mut crossings = Vec::new();
+                    for pair in samples.windows(2) {
+                        let ((jd0, lon0), (jd1, lon1)) = (pair[0], pair[1]);
+                        for (line, kind) in lines {
+                            let before = longitude_difference(line, lon0);
+                            let after = longitude_difference(line, lon1);
+                            if (before < 0.0) != (after < 0.0) && (before - after).abs() < 180.0 {
+                                crossings
+                                    .push((bisect_longitude(body, line, jd0, jd1, mode)?, kind));
+                            }
+                        }
+                    }
+                    crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+                    // Walk the crossings, opening and closing in-orb visits
+                    let in_orb_at_start =
+                        longitude_difference(target, samples[0].1).abs() <= options.orb;
+                    let mut current = in_orb_at_start.then(|| TransitEvent {
+                        transiting: body,
+                        natal: entity.clone(),
+                        aspect_type,
+                        enter_orb: None,
+                        exact: Vec::new(),
+                        leave_orb: None,
+                        search_start: start,
+                        search_end: end,
+                    });
+
+                    for (jd, kind) in crossings {
+                        match (kind, current.take()) {
+                            (Crossing::Exact, Some(mut event)) => {
+                                event.exact.push(jd_to_datetime(jd));
+                                current = Some(event);
+                            }
+                            (Crossing::Exact, None) => {}
+                            (Crossing::OrbEdge, Some(mut event)) => {
+                                event.leave_orb = Some(jd_to_datetime(jd));
+                                events.push(event);
+                            }
+                            (Crossing::OrbEdge, None) => {
+                                current = Some(TransitEvent {
+                                    transiting: body,
+                                    natal: entity.clone(),
+                                    aspect_type,
+                                    enter_orb: Some(jd_to_datetime(jd)),
+                                    exact: Vec::new(),
+                                    leave_orb: None,
+                                    search_start: start,
+                                    search_end: end,
+                                });
+                            }
+                        }
+                    }
+                    events.extend(current);
+                }
+            }
+        }
+    }
+
+    events.sort_by_key(|e| e.sort_time());
+    Ok(events)
+}
+
+/// One line per enter-orb / exact / leave-orb moment, in time order
+pub fn transit_calendar_lines(events: &[TransitEvent]) -> Vec<(DateTime<Utc>, String)> {
+    let mut lines = Vec::new();
+    for event in events {
+        let description = event.description();
+        match event.enter_orb {
+            Some(enter) => lines.push((enter, format!("{} enters orb", description))),
+            None => lines.push((
+                event.search_start,
+                format!("{} already in orb", description),
+            )),
+        }
+        let passes = event.exact.len();
+        for (i, exact) in event.exact.iter().enumerate() {
+            if passes > 1 {
+                lines.push((
+                    *exact,
+                    format!("{} exact (pass {} of {})", description, i + 1, passes),
+                ));
+            } else {
+                lines.push((*exact, format!("{} exact", description)));
+            }
+        }
+        match event.leave_orb {
+            Some(leave) => lines.push((leave, format!("{} leaves orb", description))),
+            None => lines.push((event.search_end, format!("{} still in orb", description))),
+        }
+    }
+    lines.sort_by_key(|(datetime, _)| *datetime);
+    lines
+}
+
+/// CSV with one row per transit visit (exact hits separated by ';')
+pub fn transit_calendar_csv(events: &[TransitEvent]) -> String {
+    let timestamp = |dt: &Option<DateTime<Utc>>| dt.map(|d| d.to_rfc3339()).unwrap_or_default();
+
+    let mut rows = vec!["transiting,aspect,natal,enter_orb,exact,leave_orb".to_string()];
+    for event in events {
+        let exact = event
+            .exact
+            .iter()
+            .map(|d| d.to_rfc3339())
+            .collect::<Vec<_>>()
+            .join(";");
+        rows.push(
+            [
+                csv_field(&event.transiting.to_string()),
+                csv_field(event.aspect_type.symbol()),
+                csv_field(&event.natal.to_string()),
+                timestamp(&event.enter_orb),
+                exact,
+                timestamp(&event.leave_orb),
+            ]
+            .join(","),
+        );
+    }
+    rows.join("\n")
+}
+
+/// iCalendar with one event per visit (enter to leave orb) listing the exact hits
+pub fn transit_calendar_ics(events: &[TransitEvent]) -> String {
+    let entries: Vec<CalendarEvent> = events
+        .iter()
+        .map(|event| {
+            let exact = event
+                .exact
+                .iter()
+                .map(|d| format!("Exact {} UTC", d.format("%Y-%m-%d %H:%M")))
+                .collect::<Vec<_>>()
+                .join("\n");
+            CalendarEvent {
+                start: event.sort_time(),
+                end: Some(event.end_time()),
+                summary: event.description(),
+                description: exact,
+            }
+        })
+        .collect();
+    events_to_ics(&entries, "Transits")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sweph::init_sweph;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_saturn_transit_passes() {
+        init_sweph();
+        let birth = Utc.with_ymd_and_hms(1985, 3, 10, 8, 15, 0).unwrap();
+        let input = ChartInput::new(birth, 51.5074, -0.1278).with_bodies(vec![
+            CelestialBody::Sun,
+            CelestialBody::Moon,
+            CelestialBody::Saturn,
+        ]);
+        let options = TransitSearchOptions::new()
+            .with_bodies(vec![CelestialBody::Saturn])
+            .with_aspects(vec![
+                AspectType::Conjunction,
+                AspectType::Square,
+                AspectType::Opposition,
+            ]);
+
+        let start = Utc.with_ymd_and_hms(2010, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let events = search_transits(&input, start, end, &options).unwrap();
+
+        // Saturn returns to its natal place ~29.5 years after birth (2014-2015)
+        let saturn = AspectEntity::Body(CelestialBody::Saturn);
+        let saturn_return = events
+            .iter()
+            .find(|e| e.natal == saturn && e.aspect_type == AspectType::Conjunction)
+            .unwrap();
+        assert!(saturn_return.exact.len() == 1 || saturn_return.exact.len() == 3);
+        assert!(saturn_return.enter_orb.unwrap() < saturn_return.exact[0]);
+        assert!(saturn_return.leave_orb.unwrap() > *saturn_return.exact.last().unwrap());
+
+        for event in &events {
+            assert!(event.exact.len() <= 3);
+        }
+        assert!(events
+            .windows(2)
+            .all(|w| w[0].sort_time() <= w[1].sort_time()));
+        assert_eq!(
+            transit_calendar_csv(&events).lines().count(),
+            events.len() + 1
+        );
+    }
+
+    #[test]
+    fn test_event_in_orb_for_whole_window() {
+        init_sweph();
+        let birth = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+        let input =
+            ChartInput::new(birth, 51.5074, -0.1278).with_bodies(vec![CelestialBody::Pluto]);
+        let options = TransitSearchOptions::new()
+            .with_bodies(vec![CelestialBody::Pluto])
+            .with_aspects(vec![AspectType::Conjunction]);
+
+        // Pluto barely moves in two days, so it stays conjunct its natal place throughout
+        let start = Utc.with_ymd_and_hms(2000, 1, 2, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2000, 1, 4, 0, 0, 0).unwrap();
+        let events = search_transits(&input, start, end, &options).unwrap();
+
+        let pluto = AspectEntity::Body(CelestialBody::Pluto);
+        let event = events.iter().find(|e| e.natal == pluto).unwrap();
+        assert!(event.enter_orb.is_none() && event.exact.is_empty() && event.leave_orb.is_none());
+        assert_eq!(event.sort_time(), start);
+        assert_eq!(event.end_time(), end);
+
+        let lines = transit_calendar_lines(&events);
+        assert!(lines.contains(&(start, format!("{} already in orb", event.description()))));
+        assert!(lines.contains(&(end, format!("{} still in orb", event.description()))));
+
+        let ics = transit_calendar_ics(&events);
+        assert!(ics.contains("DTSTART:20000102T000000Z"));
+        assert!(ics.contains("DTEND:20000104T000000Z"));
+    }
+}